@@ -0,0 +1,459 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use std::collections::HashSet;
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::{
+    Attribute, Data, Expr, Field, Fields, Generics, Ident, Lit, Meta, NestedMeta, Path, Token, Type, WherePredicate,
+};
+
+const DERIVABLE_TRAITS: [&str; 14] = [
+    "Clone",
+    "Copy",
+    "Debug",
+    "Default",
+    "Display",
+    "Eq",
+    "From",
+    "Hash",
+    "Ord",
+    "PartialEq",
+    "PartialOrd",
+    "Send",
+    "Sync",
+    "Unpin",
+];
+
+const SKIPPABLE_TRAITS: [&str; 4] = ["Hash", "Ord", "PartialEq", "PartialOrd"];
+
+const UNSAFE_TRAITS: [&str; 2] = ["Send", "Sync"];
+
+fn parse_force_derive_arguments(attrs: &[Attribute]) -> syn::Result<Vec<NestedMeta>> {
+    let mut result = Vec::new();
+
+    for attr in attrs {
+        if attr.path.is_ident("force_derive") {
+            if let Meta::List(list) = attr.parse_meta()? {
+                result.extend(list.nested);
+            } else {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "Expected a list of arguments like `#[force_derive(...)]`.",
+                ));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn parse_trait_name(path: &Path, candidates: &[&str]) -> syn::Result<String> {
+    path.get_ident()
+        .map(ToString::to_string)
+        .filter(|name| candidates.contains(&name.as_str()))
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                path,
+                format!(
+                    "Expected one of {}.",
+                    candidates
+                        .iter()
+                        .map(|name| format!("`{}`", name))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )
+        })
+}
+
+pub struct ContainerAttributes {
+    bounds: Vec<(String, Punctuated<WherePredicate, Token![,]>)>,
+    perfect: bool,
+    unsafe_traits: Vec<String>,
+}
+
+impl ContainerAttributes {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut bounds = Vec::new();
+        let mut perfect = false;
+        let mut unsafe_traits = Vec::new();
+
+        for argument in parse_force_derive_arguments(attrs)? {
+            match argument {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("perfect") => perfect = true,
+                NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("bound") => {
+                    for nested in list.nested {
+                        if let NestedMeta::Meta(Meta::NameValue(name_value)) = &nested {
+                            let trait_name = parse_trait_name(&name_value.path, &DERIVABLE_TRAITS)?;
+
+                            if let Lit::Str(predicates) = &name_value.lit {
+                                bounds.push((trait_name, predicates.parse_with(Punctuated::parse_terminated)?));
+                            } else {
+                                return Err(syn::Error::new_spanned(
+                                    &name_value.lit,
+                                    "Expected a string of where predicates.",
+                                ));
+                            }
+                        } else {
+                            return Err(syn::Error::new_spanned(
+                                nested,
+                                "Expected a bound like `Trait = \"T: Trait\"`.",
+                            ));
+                        }
+                    }
+                }
+                NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("unsafe") => {
+                    for nested in list.nested {
+                        if let NestedMeta::Meta(Meta::Path(path)) = &nested {
+                            unsafe_traits.push(parse_trait_name(path, &UNSAFE_TRAITS)?);
+                        } else {
+                            return Err(syn::Error::new_spanned(nested, "Expected a trait name."));
+                        }
+                    }
+                }
+                argument => {
+                    return Err(syn::Error::new_spanned(
+                        argument,
+                        "Unknown `force_derive` container argument.",
+                    ))
+                }
+            }
+        }
+
+        Ok(Self {
+            bounds,
+            perfect,
+            unsafe_traits,
+        })
+    }
+
+    pub fn perfect(&self) -> bool {
+        self.perfect
+    }
+
+    /// Returns whether the user acknowledged the safety requirements of `trait_name` with
+    /// `#[force_derive(unsafe(...))]`.
+    pub fn acknowledges_unsafe(&self, trait_name: &str) -> bool {
+        self.unsafe_traits.iter().any(|name| name == trait_name)
+    }
+
+    pub fn bounds<'a>(&'a self, trait_name: &'a str) -> impl Iterator<Item = &'a WherePredicate> {
+        self.bounds
+            .iter()
+            .filter(move |(name, _)| name == trait_name)
+            .flat_map(|(_, predicates)| predicates)
+    }
+}
+
+fn mentions_any(tokens: TokenStream, idents: &HashSet<&Ident>) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => idents.contains(&ident),
+        TokenTree::Group(group) => mentions_any(group.stream(), idents),
+        _ => false,
+    })
+}
+
+/// Appends the where predicates specified with `#[force_derive(bound(...))]` for `trait_name` to `generics`. With
+/// `#[force_derive(perfect)]`, every field type in `field_types` that mentions a type parameter is also required to
+/// implement `trait_path`.
+pub fn apply_bounds<'a>(
+    mut generics: Generics,
+    attrs: &[Attribute],
+    trait_name: &str,
+    trait_path: TokenStream,
+    field_types: impl IntoIterator<Item = &'a Type>,
+) -> syn::Result<Generics> {
+    let attributes = ContainerAttributes::parse(attrs)?;
+    let mut predicates = attributes.bounds(trait_name).cloned().collect::<Vec<_>>();
+
+    if attributes.perfect {
+        let type_parameters = generics.type_params().map(|param| &param.ident).collect::<HashSet<_>>();
+        let mut visited = HashSet::new();
+
+        for field_type in field_types {
+            if mentions_any(field_type.to_token_stream(), &type_parameters)
+                && visited.insert(field_type.to_token_stream().to_string())
+            {
+                predicates.push(syn::parse_quote!(#field_type: #trait_path));
+            }
+        }
+    }
+
+    if !predicates.is_empty() {
+        generics.make_where_clause().predicates.extend(predicates);
+    }
+
+    Ok(generics)
+}
+
+enum Skip {
+    None,
+    All,
+    Traits(Vec<String>),
+}
+
+pub struct FieldAttributes {
+    skip: Skip,
+    skip_tokens: TokenStream,
+    key: Option<Expr>,
+}
+
+impl FieldAttributes {
+    pub fn parse(field: &Field) -> syn::Result<Self> {
+        let mut skip = Skip::None;
+        let mut skip_tokens = TokenStream::new();
+        let mut key = None;
+
+        for argument in parse_force_derive_arguments(&field.attrs)? {
+            match argument {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => {
+                    path.to_tokens(&mut skip_tokens);
+                    skip = Skip::All;
+                }
+                NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("skip") => {
+                    list.to_tokens(&mut skip_tokens);
+
+                    let mut traits = match skip {
+                        Skip::Traits(traits) => traits,
+                        _ => Vec::new(),
+                    };
+
+                    for nested in list.nested {
+                        if let NestedMeta::Meta(Meta::Path(path)) = &nested {
+                            traits.push(parse_trait_name(path, &SKIPPABLE_TRAITS)?);
+                        } else {
+                            return Err(syn::Error::new_spanned(nested, "Expected a trait name."));
+                        }
+                    }
+
+                    skip = Skip::Traits(traits);
+                }
+                NestedMeta::Meta(Meta::NameValue(name_value)) if name_value.path.is_ident("key") => {
+                    if let Lit::Str(expression) = &name_value.lit {
+                        key = Some(expression.parse()?);
+                    } else {
+                        return Err(syn::Error::new_spanned(
+                            &name_value.lit,
+                            "Expected a string of a key function.",
+                        ));
+                    }
+                }
+                argument => {
+                    return Err(syn::Error::new_spanned(
+                        argument,
+                        "Unknown `force_derive` field argument.",
+                    ))
+                }
+            }
+        }
+
+        Ok(Self { skip, skip_tokens, key })
+    }
+
+    pub fn skips(&self, trait_name: &str) -> bool {
+        match &self.skip {
+            Skip::None => false,
+            Skip::All => SKIPPABLE_TRAITS.contains(&trait_name),
+            Skip::Traits(traits) => traits.iter().any(|name| name == trait_name),
+        }
+    }
+}
+
+fn check_skip_consistency(attributes: &FieldAttributes, trait_name: &str) -> syn::Result<()> {
+    let skipped = attributes.skips(trait_name);
+    let skipped_in_partial_eq = attributes.skips("PartialEq");
+
+    let consistent = match trait_name {
+        "Hash" => skipped || !skipped_in_partial_eq,
+        "Ord" | "PartialOrd" => skipped == skipped_in_partial_eq,
+        _ => true,
+    };
+
+    if consistent {
+        Ok(())
+    } else if skipped_in_partial_eq {
+        Err(syn::Error::new_spanned(
+            &attributes.skip_tokens,
+            format!(
+                "A field skipped in `PartialEq` must also be skipped in `{}`.",
+                trait_name
+            ),
+        ))
+    } else {
+        Err(syn::Error::new_spanned(
+            &attributes.skip_tokens,
+            format!(
+                "A field skipped in `{}` must also be skipped in `PartialEq`.",
+                trait_name
+            ),
+        ))
+    }
+}
+
+/// Values that are equal according to a custom `#[partial_eq(with = ...)]` function may not be identical, so hashing
+/// such a field with the `Hash` implementation of its type could give equal values different hashes, and ordering it
+/// with the order of its type could order equal values differently.
+fn check_with_consistency(field: &Field, attributes: &FieldAttributes, trait_name: &str) -> syn::Result<()> {
+    if attributes.skips(trait_name) {
+        return Ok(());
+    }
+
+    match trait_name {
+        "Hash" if parse_with_argument(field, "hash")?.is_none() => {
+            if let Some(with) = parse_with_argument(field, "partial_eq")? {
+                return Err(syn::Error::new_spanned(
+                    with,
+                    "A field compared with `#[partial_eq(with = ...)]` must also be hashed with `#[hash(with = ...)]` \
+                     or skipped in `Hash`.",
+                ));
+            }
+        }
+        "Ord" | "PartialOrd" => {
+            if let Some(with) = parse_with_argument(field, "partial_eq")? {
+                return Err(syn::Error::new_spanned(
+                    with,
+                    format!(
+                        "Cannot derive `{}` for a field compared with `#[partial_eq(with = ...)]`, which would be \
+                         ordered inconsistently with `PartialEq`.",
+                        trait_name
+                    ),
+                ));
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Returns the fields that take part in the implementation of `trait_name`, paired with their positions.
+pub fn included_fields<'a>(fields: &'a Fields, trait_name: &str) -> syn::Result<Vec<(usize, &'a Field)>> {
+    let mut result = Vec::new();
+
+    for (i, field) in fields.iter().enumerate() {
+        let attributes = FieldAttributes::parse(field)?;
+
+        check_skip_consistency(&attributes, trait_name)?;
+        check_with_consistency(field, &attributes, trait_name)?;
+
+        if !attributes.skips(trait_name) {
+            result.push((i, field));
+        }
+    }
+
+    Ok(result)
+}
+
+/// Returns all fields in `data` that take part in the implementation of `trait_name`.
+pub fn all_included_fields<'a>(data: &'a Data, trait_name: &str) -> syn::Result<Vec<&'a Field>> {
+    let mut result = Vec::new();
+
+    match data {
+        Data::Struct(data_struct) => result.extend(
+            included_fields(&data_struct.fields, trait_name)?
+                .into_iter()
+                .map(|(_, field)| field),
+        ),
+        Data::Enum(data_enum) => {
+            for variant in &data_enum.variants {
+                result.extend(
+                    included_fields(&variant.fields, trait_name)?
+                        .into_iter()
+                        .map(|(_, field)| field),
+                );
+            }
+        }
+        Data::Union(data_union) => result.extend(&data_union.fields.named),
+    }
+
+    Ok(result)
+}
+
+/// Returns the types of all fields in `data` that take part in the implementation of `trait_name`.
+pub fn included_field_types<'a>(data: &'a Data, trait_name: &str) -> syn::Result<Vec<&'a Type>> {
+    Ok(all_included_fields(data, trait_name)?
+        .into_iter()
+        .map(|field| &field.ty)
+        .collect())
+}
+
+/// Returns the types of the fields in `data` that take part in the implementation of `trait_name` through the trait
+/// implementation of their own type, rather than through a key projection or a custom function given with
+/// `#[<with_attribute>(with = ...)]`.
+pub fn bounded_field_types<'a>(
+    data: &'a Data,
+    trait_name: &str,
+    with_attribute: Option<&str>,
+) -> syn::Result<Vec<&'a Type>> {
+    let mut result = Vec::new();
+
+    for field in all_included_fields(data, trait_name)? {
+        let with = match with_attribute {
+            Some(attribute_name) => parse_with_argument(field, attribute_name)?,
+            None => None,
+        };
+
+        if with.is_none() && FieldAttributes::parse(field)?.key.is_none() {
+            result.push(&field.ty);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Applies the key projection given with `#[force_derive(key = "...")]` to `reference`, which is an expression of a
+/// reference to `field`. The key function is called through a helper function so closure parameter types can be
+/// inferred, and the named lifetime of the helper lets the key borrow from the field. The helper has a prefixed name
+/// so it does not shadow items used by the key expression.
+pub fn project_key(field: &Field, reference: TokenStream) -> syn::Result<TokenStream> {
+    let ty = &field.ty;
+
+    Ok(match FieldAttributes::parse(field)?.key {
+        Some(key) => quote::quote! {
+            &{
+                fn __force_derive_project_key<'a, T: ?::core::marker::Sized, K>(
+                    value: &'a T,
+                    key: impl ::core::ops::FnOnce(&'a T) -> K,
+                ) -> K {
+                    key(value)
+                }
+
+                __force_derive_project_key::<#ty, _>(#reference, #key)
+            }
+        },
+        None => reference,
+    })
+}
+
+/// Parses `#[<attribute_name>(with = path)]` on `field`, which names a custom function used instead of the trait
+/// implementation of the field type.
+pub fn parse_with_argument(field: &Field, attribute_name: &str) -> syn::Result<Option<Path>> {
+    let mut result = None;
+
+    for attr in field.attrs.iter().filter(|attr| attr.path.is_ident(attribute_name)) {
+        result = Some(attr.parse_args_with(|input: ParseStream| {
+            let name = input.parse::<Ident>()?;
+
+            if name == "with" {
+                input.parse::<Token![=]>()?;
+                input.parse()
+            } else {
+                Err(syn::Error::new(
+                    name.span(),
+                    format!("Unknown `{}` field argument.", attribute_name),
+                ))
+            }
+        })?);
+    }
+
+    if let Some(with) = &result {
+        if FieldAttributes::parse(field)?.key.is_some() {
+            return Err(syn::Error::new_spanned(
+                with,
+                "A field with a `key` projection cannot also have a `with` function.",
+            ));
+        }
+    }
+
+    Ok(result)
+}
@@ -1,9 +1,18 @@
-use crate::utilities;
-use proc_macro2::TokenStream;
+use crate::{attributes, sized, utilities};
+use proc_macro2::{Span, TokenStream};
 use syn::{Data, DeriveInput, Fields, Generics, Ident, Index};
 
-fn derive_with(ty: Ident, generics: Generics, body: TokenStream) -> TokenStream {
+fn derive_with(ty: Ident, generics: Generics, body: TokenStream, clone_from_body: Option<TokenStream>) -> TokenStream {
     let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let source = utilities::get_local_variable("source");
+
+    let clone_from = clone_from_body.map(|clone_from_body| {
+        quote::quote! {
+            fn clone_from(&mut self, #source: &Self) {
+                #clone_from_body
+            }
+        }
+    });
 
     quote::quote! {
         #[automatically_derived]
@@ -13,16 +22,117 @@ fn derive_with(ty: Ident, generics: Generics, body: TokenStream) -> TokenStream
             fn clone(&self) -> Self {
                 #body
             }
+
+            #clone_from
+        }
+    }
+}
+
+/// Generates the body of `clone_from`, which clones fields in place so their allocations can be reused. Enum values
+/// of different variants fall back to `*self = source.clone()`. Unions use the default implementation.
+fn generate_clone_from_body(data: &Data) -> Option<TokenStream> {
+    let clone_from = quote::quote!(::core::clone::Clone::clone_from);
+    let source = utilities::get_local_variable("source");
+
+    match data {
+        Data::Struct(data_struct) => {
+            let fields = data_struct
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| utilities::get_member(i, field));
+
+            Some(quote::quote! { #(#clone_from(&mut self.#fields, &#source.#fields);)* })
+        }
+        Data::Enum(data_enum) => {
+            let variants = &data_enum.variants;
+
+            if variants.is_empty() {
+                return Some(quote::quote! { match *self {} });
+            }
+
+            let arms = variants.iter().map(|variant| {
+                let variant_name = &variant.ident;
+
+                match &variant.fields {
+                    Fields::Named(fields) => {
+                        let field_names = fields
+                            .named
+                            .iter()
+                            .map(|field| field.ident.as_ref().unwrap())
+                            .collect::<Vec<_>>();
+
+                        let self_variables = field_names
+                            .iter()
+                            .map(|field| quote::format_ident!("self_{}", field, span = Span::mixed_site()))
+                            .collect::<Vec<_>>();
+
+                        let source_variables = field_names
+                            .iter()
+                            .map(|field| quote::format_ident!("source_{}", field, span = Span::mixed_site()))
+                            .collect::<Vec<_>>();
+
+                        quote::quote! {
+                            (
+                                Self::#variant_name { #(#field_names: #self_variables,)* },
+                                Self::#variant_name { #(#field_names: #source_variables,)* },
+                            ) => {
+                                #(#clone_from(#self_variables, #source_variables);)*
+                            }
+                        }
+                    }
+                    Fields::Unnamed(fields) => {
+                        let self_variables = (0..fields.unnamed.len())
+                            .map(|i| quote::format_ident!("self_{}", i, span = Span::mixed_site()))
+                            .collect::<Vec<_>>();
+
+                        let source_variables = (0..fields.unnamed.len())
+                            .map(|i| quote::format_ident!("source_{}", i, span = Span::mixed_site()))
+                            .collect::<Vec<_>>();
+
+                        quote::quote! {
+                            (Self::#variant_name(#(#self_variables,)*), Self::#variant_name(#(#source_variables,)*),) => {
+                                #(#clone_from(#self_variables, #source_variables);)*
+                            }
+                        }
+                    }
+                    Fields::Unit => quote::quote! { (Self::#variant_name, Self::#variant_name,) => {} },
+                }
+            });
+
+            let fallback = (variants.len() > 1).then(|| {
+                quote::quote! { _ => *self = ::core::clone::Clone::clone(#source), }
+            });
+
+            Some(quote::quote! {
+                match (&mut *self, #source) {
+                    #(#arms,)*
+                    #fallback
+                }
+            })
         }
+        Data::Union(_) => None,
     }
 }
 
-pub fn derive_clone(input: DeriveInput) -> TokenStream {
+pub fn derive_clone(input: DeriveInput) -> syn::Result<TokenStream> {
+    sized::check_sized(&input, "Clone")?;
+
     let clone = quote::quote!(::core::clone::Clone::clone);
 
-    derive_with(
-        input.ident,
+    let generics = attributes::apply_bounds(
         input.generics,
+        &input.attrs,
+        "Clone",
+        quote::quote!(::core::clone::Clone),
+        attributes::included_field_types(&input.data, "Clone")?,
+    )?;
+
+    let clone_from_body = generate_clone_from_body(&input.data);
+
+    Ok(derive_with(
+        input.ident,
+        generics,
         match input.data {
             Data::Struct(data_struct) => match data_struct.fields {
                 Fields::Named(fields) => {
@@ -81,9 +191,35 @@ pub fn derive_clone(input: DeriveInput) -> TokenStream {
                     }
                 }
             }
-            Data::Union(_) => quote::quote!(*self),
+            Data::Union(data_union) => {
+                // Unions can only be cloned by copying, so a missing `Copy` implementation is reported with a
+                // dedicated message instead of a borrow checker error. The blanket implementation is not recommended, so
+                // the diagnostic does not suggest implementing the helper trait.
+                let self_type = quote::quote_spanned!(data_union.union_token.span => Self);
+
+                quote::quote! {
+                    #[diagnostic::on_unimplemented(
+                        message = "`{Self}` must implement `Copy` to derive `Clone` on a `union`.",
+                        label = "`Clone` on a `union` requires `Copy`",
+                        note = "Derive `Copy` for the `union` as well, for example with `force_derive::Copy`."
+                    )]
+                    trait CloneUnionByCopy: ::core::marker::Sized {
+                        fn copy(value: &Self) -> Self;
+                    }
+
+                    #[diagnostic::do_not_recommend]
+                    impl<T: ::core::marker::Copy> CloneUnionByCopy for T {
+                        fn copy(value: &Self) -> Self {
+                            *value
+                        }
+                    }
+
+                    <#self_type as CloneUnionByCopy>::copy(self)
+                }
+            }
         },
-    )
+        clone_from_body,
+    ))
 }
 
 #[cfg(test)]
@@ -104,6 +240,8 @@ mod tests {
                         fn clone(&self) -> Self {
                             Self {}
                         }
+
+                        fn clone_from(&mut self, source: &Self) {}
                     }
                 },
             ),
@@ -122,6 +260,10 @@ mod tests {
                                 foo: ::core::clone::Clone::clone(&self.foo),
                             }
                         }
+
+                        fn clone_from(&mut self, source: &Self) {
+                            ::core::clone::Clone::clone_from(&mut self.foo, &source.foo);
+                        }
                     }
                 },
             ),
@@ -148,6 +290,11 @@ mod tests {
                                 bar: ::core::clone::Clone::clone(&self.bar),
                             }
                         }
+
+                        fn clone_from(&mut self, source: &Self) {
+                            ::core::clone::Clone::clone_from(&mut self.foo, &source.foo);
+                            ::core::clone::Clone::clone_from(&mut self.bar, &source.bar);
+                        }
                     }
                 },
             ),
@@ -162,6 +309,8 @@ mod tests {
                         fn clone(&self) -> Self {
                             Self()
                         }
+
+                        fn clone_from(&mut self, source: &Self) {}
                     }
                 },
             ),
@@ -176,6 +325,10 @@ mod tests {
                         fn clone(&self) -> Self {
                             Self(::core::clone::Clone::clone(&self.0),)
                         }
+
+                        fn clone_from(&mut self, source: &Self) {
+                            ::core::clone::Clone::clone_from(&mut self.0, &source.0);
+                        }
                     }
                 },
             ),
@@ -198,6 +351,11 @@ mod tests {
                                 ::core::clone::Clone::clone(&self.1),
                             )
                         }
+
+                        fn clone_from(&mut self, source: &Self) {
+                            ::core::clone::Clone::clone_from(&mut self.0, &source.0);
+                            ::core::clone::Clone::clone_from(&mut self.1, &source.1);
+                        }
                     }
                 },
             ),
@@ -212,6 +370,8 @@ mod tests {
                         fn clone(&self) -> Self {
                             Self
                         }
+
+                        fn clone_from(&mut self, source: &Self) {}
                     }
                 },
             ),
@@ -226,6 +386,10 @@ mod tests {
                         fn clone(&self) -> Self {
                             match *self {}
                         }
+
+                        fn clone_from(&mut self, source: &Self) {
+                            match *self {}
+                        }
                     }
                 },
             ),
@@ -244,6 +408,14 @@ mod tests {
                                 Self::Tuple1(field_0,) => Self::Tuple1(::core::clone::Clone::clone(field_0),),
                             }
                         }
+
+                        fn clone_from(&mut self, source: &Self) {
+                            match (&mut *self, source) {
+                                (Self::Tuple1(self_0,), Self::Tuple1(source_0,),) => {
+                                    ::core::clone::Clone::clone_from(self_0, source_0);
+                                },
+                            }
+                        }
                     }
                 },
             ),
@@ -286,6 +458,32 @@ mod tests {
                                 Self::Unit => Self::Unit,
                             }
                         }
+
+                        fn clone_from(&mut self, source: &Self) {
+                            match (&mut *self, source) {
+                                (Self::Struct0 {}, Self::Struct0 {},) => {},
+                                (Self::Struct1 { foo: self_foo, }, Self::Struct1 { foo: source_foo, },) => {
+                                    ::core::clone::Clone::clone_from(self_foo, source_foo);
+                                },
+                                (
+                                    Self::Struct2 { foo: self_foo, bar: self_bar, },
+                                    Self::Struct2 { foo: source_foo, bar: source_bar, },
+                                ) => {
+                                    ::core::clone::Clone::clone_from(self_foo, source_foo);
+                                    ::core::clone::Clone::clone_from(self_bar, source_bar);
+                                },
+                                (Self::Tuple0(), Self::Tuple0(),) => {},
+                                (Self::Tuple1(self_0,), Self::Tuple1(source_0,),) => {
+                                    ::core::clone::Clone::clone_from(self_0, source_0);
+                                },
+                                (Self::Tuple2(self_0, self_1,), Self::Tuple2(source_0, source_1,),) => {
+                                    ::core::clone::Clone::clone_from(self_0, source_0);
+                                    ::core::clone::Clone::clone_from(self_1, source_1);
+                                },
+                                (Self::Unit, Self::Unit,) => {},
+                                _ => *self = ::core::clone::Clone::clone(source),
+                            }
+                        }
                     }
                 },
             ),
@@ -300,7 +498,23 @@ mod tests {
                     #[automatically_derived]
                     impl ::core::clone::Clone for Foo {
                         fn clone(&self) -> Self {
-                            *self
+                            #[diagnostic::on_unimplemented(
+                                message = "`{Self}` must implement `Copy` to derive `Clone` on a `union`.",
+                                label = "`Clone` on a `union` requires `Copy`",
+                                note = "Derive `Copy` for the `union` as well, for example with `force_derive::Copy`."
+                            )]
+                            trait CloneUnionByCopy: ::core::marker::Sized {
+                                fn copy(value: &Self) -> Self;
+                            }
+
+                            #[diagnostic::do_not_recommend]
+                            impl<T: ::core::marker::Copy> CloneUnionByCopy for T {
+                                fn copy(value: &Self) -> Self {
+                                    *value
+                                }
+                            }
+
+                            <Self as CloneUnionByCopy>::copy(self)
                         }
                     }
                 },
@@ -309,9 +523,295 @@ mod tests {
 
         for (input, expected) in test_cases {
             assert_eq!(
-                super::derive_clone(utilities::parse_derive_input(input).unwrap()).to_string(),
+                super::derive_clone(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
                 expected.to_string(),
             );
         }
     }
+
+    #[test]
+    fn test_derive_clone_bound() {
+        let test_cases = [
+            // Struct with a bound.
+            (
+                quote::quote! {
+                    #[force_derive(bound(Clone = "T: Clone", Debug = ""))]
+                    struct Foo<T> {
+                        foo: Vec<T>,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::clone::Clone for Foo<T>
+                    where
+                        T: Clone
+                    {
+                        fn clone(&self) -> Self {
+                            Self {
+                                foo: ::core::clone::Clone::clone(&self.foo),
+                            }
+                        }
+
+                        fn clone_from(&mut self, source: &Self) {
+                            ::core::clone::Clone::clone_from(&mut self.foo, &source.foo);
+                        }
+                    }
+                },
+            ),
+            // Tuple with existing generic constraints and multiple bounds.
+            (
+                quote::quote! {
+                    #[force_derive(bound(Clone = "T: Clone, U: Clone"))]
+                    struct Foo<T, U>(Vec<T>, Vec<U>)
+                    where
+                        u32: Copy;
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T, U> ::core::clone::Clone for Foo<T, U>
+                    where
+                        u32: Copy,
+                        T: Clone,
+                        U: Clone
+                    {
+                        fn clone(&self) -> Self {
+                            Self(
+                                ::core::clone::Clone::clone(&self.0),
+                                ::core::clone::Clone::clone(&self.1),
+                            )
+                        }
+
+                        fn clone_from(&mut self, source: &Self) {
+                            ::core::clone::Clone::clone_from(&mut self.0, &source.0);
+                            ::core::clone::Clone::clone_from(&mut self.1, &source.1);
+                        }
+                    }
+                },
+            ),
+            // Empty bound.
+            (
+                quote::quote! {
+                    #[force_derive(bound(Clone = ""))]
+                    struct Foo<T>(Rc<T>);
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::clone::Clone for Foo<T> {
+                        fn clone(&self) -> Self {
+                            Self(::core::clone::Clone::clone(&self.0),)
+                        }
+
+                        fn clone_from(&mut self, source: &Self) {
+                            ::core::clone::Clone::clone_from(&mut self.0, &source.0);
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_clone(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_clone_perfect() {
+        let test_cases = [
+            // Struct.
+            (
+                quote::quote! {
+                    #[force_derive(perfect)]
+                    struct Foo<T> {
+                        foo: Vec<T>,
+                        bar: Rc<T>,
+                        baz: Vec<T>,
+                        qux: u32,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::clone::Clone for Foo<T>
+                    where
+                        Vec<T>: ::core::clone::Clone,
+                        Rc<T>: ::core::clone::Clone
+                    {
+                        fn clone(&self) -> Self {
+                            Self {
+                                foo: ::core::clone::Clone::clone(&self.foo),
+                                bar: ::core::clone::Clone::clone(&self.bar),
+                                baz: ::core::clone::Clone::clone(&self.baz),
+                                qux: ::core::clone::Clone::clone(&self.qux),
+                            }
+                        }
+
+                        fn clone_from(&mut self, source: &Self) {
+                            ::core::clone::Clone::clone_from(&mut self.foo, &source.foo);
+                            ::core::clone::Clone::clone_from(&mut self.bar, &source.bar);
+                            ::core::clone::Clone::clone_from(&mut self.baz, &source.baz);
+                            ::core::clone::Clone::clone_from(&mut self.qux, &source.qux);
+                        }
+                    }
+                },
+            ),
+            // Enum with explicit bounds.
+            (
+                quote::quote! {
+                    #[force_derive(perfect, bound(Clone = "U: Copy"))]
+                    enum Foo<T, U> {
+                        Tuple(Vec<T>),
+                        Struct { foo: Option<U> },
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T, U> ::core::clone::Clone for Foo<T, U>
+                    where
+                        U: Copy,
+                        Vec<T>: ::core::clone::Clone,
+                        Option<U>: ::core::clone::Clone
+                    {
+                        fn clone(&self) -> Self {
+                            match self {
+                                Self::Tuple(field_0,) => Self::Tuple(::core::clone::Clone::clone(field_0),),
+                                Self::Struct { foo, } => Self::Struct { foo: ::core::clone::Clone::clone(foo), },
+                            }
+                        }
+
+                        fn clone_from(&mut self, source: &Self) {
+                            match (&mut *self, source) {
+                                (Self::Tuple(self_0,), Self::Tuple(source_0,),) => {
+                                    ::core::clone::Clone::clone_from(self_0, source_0);
+                                },
+                                (Self::Struct { foo: self_foo, }, Self::Struct { foo: source_foo, },) => {
+                                    ::core::clone::Clone::clone_from(self_foo, source_foo);
+                                },
+                                _ => *self = ::core::clone::Clone::clone(source),
+                            }
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_clone(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_clone_wrong_bound_attribute() {
+        let test_cases = [
+            quote::quote! {
+                #[force_derive(bound(Unknown = "T: Clone"))]
+                struct Foo<T>(Vec<T>);
+            },
+            quote::quote! {
+                #[force_derive(bound(Clone = "T Clone"))]
+                struct Foo<T>(Vec<T>);
+            },
+            quote::quote! {
+                #[force_derive(bound(Clone = 1))]
+                struct Foo<T>(Vec<T>);
+            },
+            quote::quote! {
+                #[force_derive(bound(Clone))]
+                struct Foo<T>(Vec<T>);
+            },
+            quote::quote! {
+                #[force_derive(skip)]
+                struct Foo<T>(Vec<T>);
+            },
+        ];
+
+        for input in test_cases {
+            assert!(super::derive_clone(utilities::parse_derive_input(input).unwrap()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_derive_clone_sized_bound() {
+        let input = quote::quote! {
+            #[force_derive(bound(Clone = "H: Sized + Clone"))]
+            struct Packet<H: ?Sized> {
+                len: u32,
+                header: H,
+            }
+        };
+
+        let expected = quote::quote! {
+            #[automatically_derived]
+            impl<H: ?Sized> ::core::clone::Clone for Packet<H>
+            where
+                H: Sized + Clone
+            {
+                fn clone(&self) -> Self {
+                    Self {
+                        len: ::core::clone::Clone::clone(&self.len),
+                        header: ::core::clone::Clone::clone(&self.header),
+                    }
+                }
+
+                fn clone_from(&mut self, source: &Self) {
+                    ::core::clone::Clone::clone_from(&mut self.len, &source.len);
+                    ::core::clone::Clone::clone_from(&mut self.header, &source.header);
+                }
+            }
+        };
+
+        assert_eq!(
+            super::derive_clone(utilities::parse_derive_input(input).unwrap())
+                .unwrap()
+                .to_string(),
+            expected.to_string(),
+        );
+    }
+
+    #[test]
+    fn test_derive_clone_unsized() {
+        let test_cases = [
+            quote::quote! {
+                struct Foo {
+                    len: u32,
+                    data: str,
+                }
+            },
+            quote::quote! {
+                struct Foo<T>(u32, [T]);
+            },
+            quote::quote! {
+                struct Foo(u32, dyn Fn());
+            },
+            quote::quote! {
+                struct Foo<H: ?Sized> {
+                    len: u32,
+                    header: H,
+                }
+            },
+            quote::quote! {
+                struct Foo<H>(u32, H)
+                where
+                    H: ?Sized;
+            },
+            quote::quote! {
+                #[force_derive(bound(Clone = "T: Clone"))]
+                struct Foo<T, H: ?Sized>(T, H);
+            },
+        ];
+
+        for input in test_cases {
+            assert!(super::derive_clone(utilities::parse_derive_input(input).unwrap()).is_err());
+        }
+    }
 }
@@ -1,8 +1,10 @@
 use proc_macro2::TokenStream;
 use syn::DeriveInput;
 
-pub fn derive_copy(input: DeriveInput) -> TokenStream {
-    crate::marker_trait::derive_marker_trait(input, quote::quote!(::core::marker::Copy))
+pub fn derive_copy(input: DeriveInput) -> syn::Result<TokenStream> {
+    crate::sized::check_sized(&input, "Copy")?;
+
+    crate::marker_trait::derive_marker_trait(input, "Copy", quote::quote!(::core::marker::Copy))
 }
 
 #[cfg(test)]
@@ -163,7 +165,9 @@ mod tests {
 
         for (input, expected) in test_cases {
             assert_eq!(
-                super::derive_copy(utilities::parse_derive_input(input).unwrap()).to_string(),
+                super::derive_copy(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
                 expected.to_string(),
             );
         }
@@ -1,143 +1,452 @@
-use crate::utilities;
-use proc_macro2::TokenStream;
-use syn::{Data, DeriveInput, Fields, Generics, Ident, Index};
+use crate::{attributes, utilities};
+use proc_macro2::{Span, TokenStream};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Data, DeriveInput, Field, Fields, Generics, Ident, LitStr, Path, Token, Type};
 
-fn derive_with(ty: Ident, generics: Generics, body: TokenStream) -> TokenStream {
+enum FieldArgument {
+    Skip,
+    Rename(LitStr),
+    With(Path),
+}
+
+impl Parse for FieldArgument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<Ident>()?;
+
+        if name == "skip" {
+            Ok(Self::Skip)
+        } else if name == "rename" {
+            input.parse::<Token![=]>()?;
+
+            Ok(Self::Rename(input.parse()?))
+        } else if name == "with" {
+            input.parse::<Token![=]>()?;
+
+            Ok(Self::With(input.parse()?))
+        } else {
+            Err(syn::Error::new(name.span(), "Unknown `debug` field argument."))
+        }
+    }
+}
+
+enum ContainerArgument {
+    Transparent(Ident),
+    SkipPhantom,
+}
+
+impl Parse for ContainerArgument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<Ident>()?;
+
+        if name == "transparent" {
+            Ok(Self::Transparent(name))
+        } else if name == "skip_phantom" {
+            Ok(Self::SkipPhantom)
+        } else {
+            Err(syn::Error::new(name.span(), "Unknown `debug` container argument."))
+        }
+    }
+}
+
+/// `debug` attributes of a type or an enum variant, together with whether it is marked `#[non_exhaustive]`.
+#[derive(Default)]
+struct ContainerAttributes {
+    transparent: Option<Ident>,
+    skip_phantom: bool,
+    non_exhaustive: bool,
+}
+
+impl ContainerAttributes {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self {
+            non_exhaustive: attrs.iter().any(|attr| attr.path.is_ident("non_exhaustive")),
+            ..Self::default()
+        };
+
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("debug")) {
+            for argument in attr.parse_args_with(Punctuated::<ContainerArgument, Token![,]>::parse_terminated)? {
+                match argument {
+                    ContainerArgument::Transparent(name) => result.transparent = Some(name),
+                    ContainerArgument::SkipPhantom => result.skip_phantom = true,
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Parses the attributes of an enum variant, which inherits `skip_phantom` from the enum.
+    fn parse_variant(attrs: &[Attribute], enum_attributes: &Self) -> syn::Result<Self> {
+        let mut result = Self::parse(attrs)?;
+
+        result.skip_phantom |= enum_attributes.skip_phantom;
+
+        Ok(result)
+    }
+
+    fn omits(&self, field: &Field) -> bool {
+        self.skip_phantom && utilities::is_phantom_data(&field.ty)
+    }
+}
+
+#[derive(Default)]
+struct FieldAttributes {
+    skip: bool,
+    rename: Option<LitStr>,
+    with: Option<Path>,
+}
+
+impl FieldAttributes {
+    fn parse(field: &Field) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        for attr in field.attrs.iter().filter(|attr| attr.path.is_ident("debug")) {
+            for argument in attr.parse_args_with(Punctuated::<FieldArgument, Token![,]>::parse_terminated)? {
+                match argument {
+                    FieldArgument::Skip => result.skip = true,
+                    FieldArgument::Rename(name) => {
+                        if field.ident.is_none() {
+                            return Err(syn::Error::new_spanned(name, "Only named fields can be renamed."));
+                        }
+
+                        result.rename = Some(name);
+                    }
+                    FieldArgument::With(path) => result.with = Some(path),
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Returns the fields that are printed, paired with their positions and `debug` attributes.
+fn included_fields<'a>(
+    fields: &'a Fields,
+    container_attributes: &ContainerAttributes,
+) -> syn::Result<Vec<(usize, &'a Field, FieldAttributes)>> {
+    let mut result = Vec::new();
+
+    for (i, field) in fields.iter().enumerate() {
+        let attributes = FieldAttributes::parse(field)?;
+
+        if !attributes.skip && !container_attributes.omits(field) {
+            result.push((i, field, attributes));
+        }
+    }
+
+    Ok(result)
+}
+
+/// Returns the field lists in `data`, paired with the attributes of the struct or enum variant they belong to.
+fn get_field_lists<'a>(attrs: &[Attribute], data: &'a Data) -> syn::Result<Vec<(&'a Fields, ContainerAttributes)>> {
+    let container_attributes = ContainerAttributes::parse(attrs)?;
+
+    Ok(match data {
+        Data::Struct(data_struct) => vec![(&data_struct.fields, container_attributes)],
+        Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .map(|variant| {
+                Ok((
+                    &variant.fields,
+                    ContainerAttributes::parse_variant(&variant.attrs, &container_attributes)?,
+                ))
+            })
+            .collect::<syn::Result<_>>()?,
+        Data::Union(_) => Vec::new(),
+    })
+}
+
+/// Returns the types of the printed fields that are formatted with their own `Debug` implementations.
+fn debug_field_types<'a>(attrs: &[Attribute], data: &'a Data) -> syn::Result<Vec<&'a Type>> {
+    let mut result = Vec::new();
+
+    for (fields, container_attributes) in get_field_lists(attrs, data)? {
+        for (_, field, attributes) in included_fields(fields, &container_attributes)? {
+            if attributes.with.is_none() {
+                result.push(&field.ty);
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn uses_with(attrs: &[Attribute], data: &Data) -> syn::Result<bool> {
+    for (fields, container_attributes) in get_field_lists(attrs, data)? {
+        if included_fields(fields, &container_attributes)?
+            .iter()
+            .any(|(_, _, attributes)| attributes.with.is_some())
+        {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+fn derive_with(ty: Ident, generics: Generics, body: TokenStream, automatically_derived: bool) -> TokenStream {
     let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let f = utilities::get_local_variable("f");
+    let attributes = automatically_derived.then(|| quote::quote!(#[automatically_derived]));
 
     quote::quote! {
-        #[automatically_derived]
+        #attributes
         impl #impl_generics ::core::fmt::Debug for #ty #type_generics
         #where_clause
         {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            fn fmt(&self, #f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                 #body
             }
         }
     }
 }
 
+/// Defines a `__ForceDeriveDebugWith` type that formats a reference with a custom formatting function. The name is
+/// prefixed so that it does not shadow user items referred to by `#[debug(with = ...)]` paths.
+fn define_debug_with() -> TokenStream {
+    quote::quote! {
+        struct __ForceDeriveDebugWith<'a, T: ?Sized>(&'a T, fn(&T, &mut ::core::fmt::Formatter) -> ::core::fmt::Result);
+
+        impl<T: ?Sized> ::core::fmt::Debug for __ForceDeriveDebugWith<'_, T> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                (self.1)(self.0, f)
+            }
+        }
+    }
+}
+
 fn write_str(type_name: &str) -> TokenStream {
-    quote::quote!(f.write_str(#type_name))
+    let f = utilities::get_local_variable("f");
+
+    quote::quote!(#f.write_str(#type_name))
+}
+
+/// Generates the expression that formats `fields` with `debug_struct` or `debug_tuple`. `references` are expressions
+/// of references to the included fields. If some fields are skipped or the container is `#[non_exhaustive]`, the
+/// output ends with `..`, like `Foo { a: 1, .. }`. Omitted `PhantomData` fields carry no data, so they do not count as
+/// skipped.
+fn debug_fields(
+    name: &str,
+    container_attributes: &ContainerAttributes,
+    fields: &Fields,
+    included_fields: &[(usize, &Field, FieldAttributes)],
+    references: &[TokenStream],
+) -> syn::Result<TokenStream> {
+    let f = utilities::get_local_variable("f");
+    let printable_fields = fields.iter().filter(|field| !container_attributes.omits(field)).count();
+    let non_exhaustive = container_attributes.non_exhaustive || included_fields.len() < printable_fields;
+
+    let finish = if non_exhaustive {
+        quote::quote!(finish_non_exhaustive)
+    } else {
+        quote::quote!(finish)
+    };
+
+    let values = included_fields
+        .iter()
+        .zip(references)
+        .map(|((_, _, attributes), reference)| match &attributes.with {
+            Some(with) => quote::quote!(&__ForceDeriveDebugWith(#reference, #with)),
+            // Double references let an unsized last field coerce to `&dyn Debug`.
+            None => quote::quote!(&#reference),
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(transparent) = &container_attributes.transparent {
+        let mut candidates = included_fields
+            .iter()
+            .zip(&values)
+            .filter(|((_, field, _), _)| !utilities::is_phantom_data(&field.ty));
+
+        let message = "`#[debug(transparent)]` requires exactly one field that is not `PhantomData`.";
+
+        return match (candidates.next(), candidates.next()) {
+            (Some((_, value)), None) => Ok(quote::quote!(::core::fmt::Debug::fmt(#value, #f))),
+            (None, _) => Err(syn::Error::new_spanned(transparent, message)),
+            (Some(_), Some(((_, field, _), _))) => Err(syn::Error::new_spanned(field, message)),
+        };
+    }
+
+    Ok(match fields {
+        Fields::Named(_) if non_exhaustive || !included_fields.is_empty() => {
+            let field_names = included_fields.iter().map(|(_, field, attributes)| {
+                attributes
+                    .rename
+                    .as_ref()
+                    .map_or_else(|| field.ident.as_ref().unwrap().to_string(), LitStr::value)
+            });
+
+            quote::quote! {
+                #f.debug_struct(#name)
+                #(.field(#field_names, #values))*
+                .#finish()
+            }
+        }
+        Fields::Unnamed(_) if non_exhaustive || !included_fields.is_empty() => {
+            quote::quote! {
+                #f.debug_tuple(#name)
+                #(.field(#values))*
+                .#finish()
+            }
+        }
+        _ => write_str(name),
+    })
 }
 
 pub fn derive_debug(input: DeriveInput) -> syn::Result<TokenStream> {
-    let span = input.ident.span();
     let ty_string = input.ident.to_string();
 
-    Ok(derive_with(
-        input.ident,
+    let generics = attributes::apply_bounds(
         input.generics,
-        match input.data {
-            Data::Struct(data_struct) => match data_struct.fields {
-                Fields::Named(fields) => {
-                    if fields.named.is_empty() {
-                        write_str(&ty_string)
-                    } else {
-                        let fields = fields.named.into_iter().map(|field| {
-                            let field = field.ident.unwrap();
-                            let field_str = field.to_string();
-
-                            quote::quote!(#field_str, &self.#field)
-                        });
-
-                        quote::quote! {
-                            f.debug_struct(#ty_string)
-                            #(.field(#fields))*
-                            .finish()
-                        }
-                    }
-                }
-                Fields::Unnamed(fields) => {
-                    if fields.unnamed.is_empty() {
-                        write_str(&ty_string)
-                    } else {
-                        let fields = (0..fields.unnamed.len()).map(Index::from);
-
-                        quote::quote! {
-                            f.debug_tuple(#ty_string)
-                            #(.field(&self.#fields))*
-                            .finish()
-                        }
-                    }
-                }
-                Fields::Unit => write_str(&ty_string),
-            },
-            Data::Enum(data_enum) => {
-                let variants = data_enum.variants;
-
-                if variants.is_empty() {
-                    quote::quote! { match *self {} }
-                } else {
-                    let arms = variants.into_iter().map(|variant| {
-                        let variant_name = variant.ident;
+        &input.attrs,
+        "Debug",
+        quote::quote!(::core::fmt::Debug),
+        debug_field_types(&input.attrs, &input.data)?,
+    )?;
+
+    let container_attributes = ContainerAttributes::parse(&input.attrs)?;
+
+    let body = match &input.data {
+        Data::Struct(data_struct) => {
+            let included_fields = included_fields(&data_struct.fields, &container_attributes)?;
+
+            let references = included_fields
+                .iter()
+                .map(|(i, field, _)| {
+                    let member = utilities::get_member(*i, field);
+
+                    quote::quote!(&self.#member)
+                })
+                .collect::<Vec<_>>();
+
+            debug_fields(
+                &ty_string,
+                &container_attributes,
+                &data_struct.fields,
+                &included_fields,
+                &references,
+            )?
+        }
+        Data::Enum(data_enum) => {
+            if let Some(transparent) = &container_attributes.transparent {
+                return Err(syn::Error::new_spanned(
+                    transparent,
+                    "Use `#[debug(transparent)]` on enum variants instead.",
+                ));
+            }
+
+            let variants = &data_enum.variants;
+
+            if variants.is_empty() {
+                quote::quote! { match *self {} }
+            } else {
+                let arms = variants
+                    .iter()
+                    .map(|variant| {
+                        let variant_name = &variant.ident;
                         let variant_name_string = variant_name.to_string();
+                        let variant_attributes =
+                            ContainerAttributes::parse_variant(&variant.attrs, &container_attributes)?;
+
+                        let included_fields = included_fields(&variant.fields, &variant_attributes)?;
 
-                        match variant.fields {
+                        Ok(match &variant.fields {
                             Fields::Named(fields) => {
-                                if fields.named.is_empty() {
-                                    let body = write_str(&variant_name_string);
-
-                                    quote::quote! {
-                                        Self::#variant_name {} => #body
-                                    }
-                                } else {
-                                    let pattern_fields = fields.named.iter().map(|field| field.ident.as_ref().unwrap());
-
-                                    let field_variables = pattern_fields
-                                        .clone()
-                                        .map(|field| quote::format_ident!("field_{}", field))
-                                        .collect::<Vec<_>>();
-
-                                    let field_names = pattern_fields.clone().map(Ident::to_string);
-
-                                    quote::quote! {
-                                        Self::#variant_name { #(#pattern_fields: #field_variables,)* } =>
-                                            f.debug_struct(#variant_name_string)
-                                            #(.field(#field_names, #field_variables))*
-                                            .finish()
-                                    }
+                                let pattern_fields = included_fields
+                                    .iter()
+                                    .map(|(_, field, _)| field.ident.as_ref().unwrap())
+                                    .collect::<Vec<_>>();
+
+                                let field_variables = pattern_fields
+                                    .iter()
+                                    .map(|field| quote::format_ident!("field_{}", field, span = Span::mixed_site()))
+                                    .collect::<Vec<_>>();
+
+                                let rest = utilities::get_rest_pattern(included_fields.len(), fields.named.len());
+
+                                let body = debug_fields(
+                                    &variant_name_string,
+                                    &variant_attributes,
+                                    &variant.fields,
+                                    &included_fields,
+                                    &field_variables
+                                        .iter()
+                                        .map(|variable| quote::quote!(#variable))
+                                        .collect::<Vec<_>>(),
+                                )?;
+
+                                quote::quote! {
+                                    Self::#variant_name { #(#pattern_fields: #field_variables,)* #rest } => #body
                                 }
                             }
                             Fields::Unnamed(fields) => {
-                                if fields.unnamed.is_empty() {
-                                    let body = write_str(&variant_name_string);
-
-                                    quote::quote! {
-                                        Self::#variant_name() => #body
-                                    }
-                                } else {
-                                    let fields =
-                                        utilities::get_field_identifiers(fields.unnamed.len()).collect::<Vec<_>>();
-
-                                    quote::quote! {
-                                        Self::#variant_name(#(#fields,)*) =>
-                                            f.debug_tuple(#variant_name_string)
-                                            #(.field(#fields))*
-                                            .finish()
-                                    }
+                                let field_variables = utilities::get_field_identifiers(fields.unnamed.len())
+                                    .enumerate()
+                                    .filter(|(i, _)| included_fields.iter().any(|(included, _, _)| included == i))
+                                    .collect::<Vec<_>>();
+
+                                let patterns = utilities::get_tuple_patterns(fields.unnamed.len(), &field_variables);
+
+                                let body = debug_fields(
+                                    &variant_name_string,
+                                    &variant_attributes,
+                                    &variant.fields,
+                                    &included_fields,
+                                    &field_variables
+                                        .iter()
+                                        .map(|(_, variable)| quote::quote!(#variable))
+                                        .collect::<Vec<_>>(),
+                                )?;
+
+                                quote::quote! {
+                                    Self::#variant_name(#(#patterns,)*) => #body
                                 }
                             }
                             Fields::Unit => {
-                                let body = write_str(&variant_name_string);
+                                let body =
+                                    debug_fields(&variant_name_string, &variant_attributes, &variant.fields, &[], &[])?;
 
                                 quote::quote! {
                                     Self::#variant_name => #body
                                 }
                             }
-                        }
-                    });
+                        })
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
 
-                    quote::quote! {
-                        match self {
-                            #(#arms,)*
-                        }
+                quote::quote! {
+                    match self {
+                        #(#arms,)*
                     }
                 }
             }
-            Data::Union(_) => return Err(syn::Error::new(span, "Cannot derive `Debug` on a `union`.")),
-        },
-    ))
+        }
+        Data::Union(data_union) => {
+            return Err(syn::Error::new(
+                data_union.union_token.span,
+                "Cannot derive `Debug` on a `union`.",
+            ))
+        }
+    };
+
+    // `#[automatically_derived]` implementations are ignored when looking for dead code, so it is left out to keep the
+    // custom formatting functions from being reported as unused.
+    Ok(if uses_with(&input.attrs, &input.data)? {
+        let debug_with = define_debug_with();
+
+        let body = quote::quote! {
+            #debug_with
+
+            #body
+        };
+
+        derive_with(input.ident, generics, body, false)
+    } else {
+        derive_with(input.ident, generics, body, true)
+    })
 }
 
 #[cfg(test)]
@@ -172,7 +481,7 @@ mod tests {
                     #[automatically_derived]
                     impl<T> ::core::fmt::Debug for Foo<T> {
                         fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                            f.debug_struct("Foo").field("foo", &self.foo).finish()
+                            f.debug_struct("Foo").field("foo", & &self.foo).finish()
                         }
                     }
                 },
@@ -196,8 +505,8 @@ mod tests {
                     {
                         fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                             f.debug_struct("Foo")
-                                .field("foo", &self.foo)
-                                .field("bar", &self.bar)
+                                .field("foo", & &self.foo)
+                                .field("bar", & &self.bar)
                                 .finish()
                         }
                     }
@@ -226,7 +535,7 @@ mod tests {
                     #[automatically_derived]
                     impl<T> ::core::fmt::Debug for Foo<T> {
                         fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                            f.debug_tuple("Foo").field(&self.0).finish()
+                            f.debug_tuple("Foo").field(& &self.0).finish()
                         }
                     }
                 },
@@ -246,8 +555,8 @@ mod tests {
                     {
                         fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                             f.debug_tuple("Foo")
-                                .field(&self.0)
-                                .field(&self.1)
+                                .field(& &self.0)
+                                .field(& &self.1)
                                 .finish()
                         }
                     }
@@ -293,7 +602,7 @@ mod tests {
                     impl<T> ::core::fmt::Debug for Foo<T> {
                         fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                             match self {
-                                Self::Tuple1(field_0,) => f.debug_tuple("Tuple1").field(field_0).finish(),
+                                Self::Tuple1(field_0,) => f.debug_tuple("Tuple1").field(&field_0).finish(),
                             }
                         }
                     }
@@ -325,19 +634,19 @@ mod tests {
                             match self {
                                 Self::Struct0 {} => f.write_str("Struct0"),
                                 Self::Struct1 { foo: field_foo, } => f.debug_struct("Struct1")
-                                    .field("foo", field_foo)
+                                    .field("foo", &field_foo)
                                     .finish(),
                                 Self::Struct2 { foo: field_foo, bar: field_bar, } => f.debug_struct("Struct2")
-                                    .field("foo", field_foo)
-                                    .field("bar", field_bar)
+                                    .field("foo", &field_foo)
+                                    .field("bar", &field_bar)
                                     .finish(),
                                 Self::Tuple0() => f.write_str("Tuple0"),
                                 Self::Tuple1(field_0,) => f.debug_tuple("Tuple1")
-                                    .field(field_0)
+                                    .field(&field_0)
                                     .finish(),
                                 Self::Tuple2(field_0, field_1,) => f.debug_tuple("Tuple2")
-                                    .field(field_0)
-                                    .field(field_1)
+                                    .field(&field_0)
+                                    .field(&field_1)
                                     .finish(),
                                 Self::Unit => f.write_str("Unit"),
                             }
@@ -356,4 +665,426 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_derive_debug_field_attributes() {
+        let test_cases = [
+            // Struct.
+            (
+                quote::quote! {
+                    struct Foo<T> {
+                        #[debug(rename = "id")]
+                        foo: u32,
+                        #[debug(skip)]
+                        bar: PhantomData<T>,
+                        #[debug(with = fmt_secret)]
+                        baz: String,
+                    }
+                },
+                quote::quote! {
+                    impl<T> ::core::fmt::Debug for Foo<T> {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            struct __ForceDeriveDebugWith<'a, T: ?Sized>(&'a T, fn(&T, &mut ::core::fmt::Formatter) -> ::core::fmt::Result);
+
+                            impl<T: ?Sized> ::core::fmt::Debug for __ForceDeriveDebugWith<'_, T> {
+                                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                                    (self.1)(self.0, f)
+                                }
+                            }
+
+                            f.debug_struct("Foo")
+                                .field("id", & &self.foo)
+                                .field("baz", &__ForceDeriveDebugWith(&self.baz, fmt_secret))
+                                .finish_non_exhaustive()
+                        }
+                    }
+                },
+            ),
+            // Enum.
+            (
+                quote::quote! {
+                    enum Foo<T> {
+                        Struct {
+                            #[debug(skip)]
+                            foo: PhantomData<T>,
+                            #[debug(rename = "Bar", with = fmt::hex)]
+                            bar: u32,
+                        },
+                        Tuple(u32, #[debug(skip)] PhantomData<T>),
+                    }
+                },
+                quote::quote! {
+                    impl<T> ::core::fmt::Debug for Foo<T> {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            struct __ForceDeriveDebugWith<'a, T: ?Sized>(&'a T, fn(&T, &mut ::core::fmt::Formatter) -> ::core::fmt::Result);
+
+                            impl<T: ?Sized> ::core::fmt::Debug for __ForceDeriveDebugWith<'_, T> {
+                                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                                    (self.1)(self.0, f)
+                                }
+                            }
+
+                            match self {
+                                Self::Struct { bar: field_bar, .. } => f.debug_struct("Struct")
+                                    .field("Bar", &__ForceDeriveDebugWith(field_bar, fmt::hex))
+                                    .finish_non_exhaustive(),
+                                Self::Tuple(field_0, _,) => f.debug_tuple("Tuple")
+                                    .field(&field_0)
+                                    .finish_non_exhaustive(),
+                            }
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_debug(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_debug_non_exhaustive() {
+        let test_cases = [
+            // Struct.
+            (
+                quote::quote! {
+                    #[non_exhaustive]
+                    struct Foo {
+                        foo: u32,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::fmt::Debug for Foo {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            f.debug_struct("Foo").field("foo", & &self.foo).finish_non_exhaustive()
+                        }
+                    }
+                },
+            ),
+            (
+                quote::quote! {
+                    #[non_exhaustive]
+                    struct Foo {}
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::fmt::Debug for Foo {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            f.debug_struct("Foo").finish_non_exhaustive()
+                        }
+                    }
+                },
+            ),
+            (
+                quote::quote! {
+                    struct Foo(#[debug(skip)] u32);
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::fmt::Debug for Foo {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            f.debug_tuple("Foo").finish_non_exhaustive()
+                        }
+                    }
+                },
+            ),
+            (
+                quote::quote! {
+                    #[non_exhaustive]
+                    struct Foo;
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::fmt::Debug for Foo {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            f.write_str("Foo")
+                        }
+                    }
+                },
+            ),
+            // Enum.
+            (
+                quote::quote! {
+                    enum Foo {
+                        #[non_exhaustive]
+                        Struct { foo: u32 },
+                        #[non_exhaustive]
+                        Tuple(u32),
+                        Unit,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::fmt::Debug for Foo {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            match self {
+                                Self::Struct { foo: field_foo, } => f.debug_struct("Struct")
+                                    .field("foo", &field_foo)
+                                    .finish_non_exhaustive(),
+                                Self::Tuple(field_0,) => f.debug_tuple("Tuple")
+                                    .field(&field_0)
+                                    .finish_non_exhaustive(),
+                                Self::Unit => f.write_str("Unit"),
+                            }
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_debug(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_debug_transparent() {
+        let test_cases = [
+            // Struct.
+            (
+                quote::quote! {
+                    #[debug(transparent)]
+                    struct Foo<T>(u64, PhantomData<T>);
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::fmt::Debug for Foo<T> {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            ::core::fmt::Debug::fmt(& &self.0, f)
+                        }
+                    }
+                },
+            ),
+            (
+                quote::quote! {
+                    #[debug(transparent)]
+                    struct Foo<T> {
+                        #[debug(skip)]
+                        foo: u32,
+                        bar: ::core::marker::PhantomData<T>,
+                        baz: u64,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::fmt::Debug for Foo<T> {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            ::core::fmt::Debug::fmt(& &self.baz, f)
+                        }
+                    }
+                },
+            ),
+            // Enum.
+            (
+                quote::quote! {
+                    enum Foo<T> {
+                        #[debug(transparent)]
+                        Struct { foo: PhantomData<T>, bar: u32 },
+                        #[debug(transparent)]
+                        Tuple(u32),
+                        Unit,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::fmt::Debug for Foo<T> {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            match self {
+                                Self::Struct { foo: field_foo, bar: field_bar, } => ::core::fmt::Debug::fmt(&field_bar, f),
+                                Self::Tuple(field_0,) => ::core::fmt::Debug::fmt(&field_0, f),
+                                Self::Unit => f.write_str("Unit"),
+                            }
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_debug(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_debug_skip_phantom() {
+        let test_cases = [
+            // Struct.
+            (
+                quote::quote! {
+                    #[debug(skip_phantom)]
+                    struct Foo<T> {
+                        foo: u32,
+                        bar: PhantomData<T>,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::fmt::Debug for Foo<T> {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            f.debug_struct("Foo").field("foo", & &self.foo).finish()
+                        }
+                    }
+                },
+            ),
+            (
+                quote::quote! {
+                    #[debug(skip_phantom)]
+                    struct Foo<T>(::core::marker::PhantomData<T>);
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::fmt::Debug for Foo<T> {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            f.write_str("Foo")
+                        }
+                    }
+                },
+            ),
+            // Enum.
+            (
+                quote::quote! {
+                    #[debug(skip_phantom)]
+                    enum Foo<T> {
+                        Struct { foo: PhantomData<T>, bar: u32 },
+                        Tuple(u32, #[debug(skip)] u32, PhantomData<T>),
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::fmt::Debug for Foo<T> {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            match self {
+                                Self::Struct { bar: field_bar, .. } => f.debug_struct("Struct").field("bar", &field_bar).finish(),
+                                Self::Tuple(field_0, _, _,) => f.debug_tuple("Tuple").field(&field_0).finish_non_exhaustive(),
+                            }
+                        }
+                    }
+                },
+            ),
+            (
+                quote::quote! {
+                    enum Foo<T> {
+                        #[debug(skip_phantom)]
+                        Tuple1(u32, PhantomData<T>),
+                        Tuple2(u32, PhantomData<T>),
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::fmt::Debug for Foo<T> {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            match self {
+                                Self::Tuple1(field_0, _,) => f.debug_tuple("Tuple1").field(&field_0).finish(),
+                                Self::Tuple2(field_0, field_1,) => f.debug_tuple("Tuple2")
+                                    .field(&field_0)
+                                    .field(&field_1)
+                                    .finish(),
+                            }
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_debug(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_debug_wrong_transparent() {
+        let test_cases = [
+            quote::quote! {
+                #[debug(transparent)]
+                struct Foo(u32, u32);
+            },
+            quote::quote! {
+                #[debug(transparent)]
+                struct Foo<T>(PhantomData<T>);
+            },
+            quote::quote! {
+                #[debug(transparent)]
+                struct Foo;
+            },
+            quote::quote! {
+                #[debug(transparent)]
+                enum Foo {
+                    Tuple(u32),
+                }
+            },
+            quote::quote! {
+                enum Foo {
+                    #[debug(transparent)]
+                    Unit,
+                }
+            },
+            quote::quote! {
+                #[debug(unknown)]
+                struct Foo(u32);
+            },
+        ];
+
+        for input in test_cases {
+            assert!(super::derive_debug(utilities::parse_derive_input(input).unwrap()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_derive_debug_wrong_field_attribute() {
+        let test_cases = [
+            quote::quote! {
+                struct Foo {
+                    #[debug(unknown)]
+                    foo: u32,
+                }
+            },
+            quote::quote! {
+                struct Foo {
+                    #[debug(rename)]
+                    foo: u32,
+                }
+            },
+            quote::quote! {
+                struct Foo {
+                    #[debug(with = "fmt_secret")]
+                    foo: u32,
+                }
+            },
+            quote::quote! {
+                struct Foo(#[debug(rename = "foo")] u32);
+            },
+            quote::quote! {
+                struct Foo {
+                    #[debug]
+                    foo: u32,
+                }
+            },
+        ];
+
+        for input in test_cases {
+            assert!(super::derive_debug(utilities::parse_derive_input(input).unwrap()).is_err());
+        }
+    }
 }
@@ -1,6 +1,8 @@
+use crate::{attributes, sized};
 use proc_macro2::TokenStream;
-use std::iter;
-use syn::{Data, DeriveInput, Fields, Generics, Ident, Meta};
+use quote::ToTokens;
+use syn::parse::{ParseStream, Parser};
+use syn::{token, Attribute, Data, DeriveInput, Expr, Field, Fields, Generics, Ident, Meta, Token, Type};
 
 fn derive_with(ty: Ident, generics: Generics, body: TokenStream) -> TokenStream {
     let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
@@ -17,31 +19,106 @@ fn derive_with(ty: Ident, generics: Generics, body: TokenStream) -> TokenStream
     }
 }
 
+fn is_default_attribute(attr: &Attribute) -> bool {
+    attr.path.is_ident("default")
+}
+
+fn duplicate_default_error(first: &Attribute, second: &Attribute, message: &str) -> syn::Error {
+    let mut error = syn::Error::new_spanned(second, message);
+
+    error.combine(syn::Error::new_spanned(first, "First `#[default]` attribute here."));
+
+    error
+}
+
+fn parse_field_default(attr: &Attribute) -> syn::Result<Expr> {
+    (|input: ParseStream| {
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+
+            input.parse()
+        } else if input.peek(token::Paren) {
+            let content;
+
+            syn::parenthesized!(content in input);
+
+            content.parse()
+        } else {
+            Err(syn::Error::new_spanned(
+                attr,
+                "Use `#[default = ...]` or `#[default(...)]` to specify the default value of a field.",
+            ))
+        }
+    })
+    .parse2(attr.tokens.clone())
+}
+
+fn field_default(default: &TokenStream, field: &Field) -> syn::Result<TokenStream> {
+    let mut attrs = field.attrs.iter().filter(|attr| is_default_attribute(attr));
+
+    match (attrs.next(), attrs.next()) {
+        (None, _) => Ok(default.clone()),
+        (Some(attr), None) => parse_field_default(attr).map(ToTokens::into_token_stream),
+        (Some(first), Some(second)) => Err(duplicate_default_error(
+            first,
+            second,
+            "Use a single `#[default]` attribute to specify the default value of a field.",
+        )),
+    }
+}
+
+fn construct(default: &TokenStream, path: TokenStream, fields: &Fields) -> syn::Result<TokenStream> {
+    Ok(match fields {
+        Fields::Named(fields) => {
+            let values = fields
+                .named
+                .iter()
+                .map(|field| field_default(default, field))
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            let fields = fields.named.iter().map(|field| field.ident.as_ref().unwrap());
+
+            quote::quote! { #path { #(#fields: #values,)* } }
+        }
+        Fields::Unnamed(fields) => {
+            let values = fields
+                .unnamed
+                .iter()
+                .map(|field| field_default(default, field))
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            quote::quote! { #path(#(#values,)*) }
+        }
+        Fields::Unit => path,
+    })
+}
+
+fn default_field_types<'a>(fields: impl IntoIterator<Item = &'a Field>) -> Vec<&'a Type> {
+    fields
+        .into_iter()
+        .filter(|field| !field.attrs.iter().any(is_default_attribute))
+        .map(|field| &field.ty)
+        .collect()
+}
+
 pub fn derive_default(input: DeriveInput) -> syn::Result<TokenStream> {
+    sized::check_sized(&input, "Default")?;
+
     let default = quote::quote!(::core::default::Default::default());
     let span = input.ident.span();
 
-    Ok(derive_with(
-        input.ident,
-        input.generics,
-        match input.data {
-            Data::Struct(data_struct) => match data_struct.fields {
-                Fields::Named(fields) => {
-                    let fields = fields.named.into_iter().map(|field| field.ident.unwrap());
-
-                    quote::quote! { Self { #(#fields: #default,)* } }
-                }
-                Fields::Unnamed(fields) => {
-                    let fields = iter::repeat(&default).take(fields.unnamed.len());
-
-                    quote::quote! { Self(#(#fields,)*) }
-                }
-                Fields::Unit => quote::quote! { Self },
-            },
-            Data::Enum(data_enum) => {
-                let mut default_variants_iter = data_enum.variants.into_iter().filter(|variant| {
-                    variant.attrs.iter().any(|attr| {
-                        attr.parse_meta().map_or(false, |meta| {
+    let (body, field_types) = match &input.data {
+        Data::Struct(data_struct) => (
+            construct(&default, quote::quote!(Self), &data_struct.fields)?,
+            default_field_types(&data_struct.fields),
+        ),
+        Data::Enum(data_enum) => {
+            let mut default_variants_iter = data_enum.variants.iter().filter_map(|variant| {
+                variant
+                    .attrs
+                    .iter()
+                    .find(|attr| {
+                        attr.parse_meta().is_ok_and(|meta| {
                             if let Meta::Path(path) = meta {
                                 path.is_ident("default")
                             } else {
@@ -49,37 +126,86 @@ pub fn derive_default(input: DeriveInput) -> syn::Result<TokenStream> {
                             }
                         })
                     })
-                });
+                    .map(|attr| (variant, attr))
+            });
 
-                if let Some(variant) = default_variants_iter
-                    .next()
-                    .filter(|_| default_variants_iter.next().is_none())
-                {
-                    let variant_name = variant.ident;
-
-                    match variant.fields {
-                        Fields::Named(fields) => {
-                            let fields = fields.named.into_iter().map(|field| field.ident.unwrap());
-
-                            quote::quote! { Self::#variant_name { #(#fields: #default,)* } }
-                        }
-                        Fields::Unnamed(fields) => {
-                            let fields = iter::repeat(&default).take(fields.unnamed.len());
+            match (default_variants_iter.next(), default_variants_iter.next()) {
+                (Some((variant, _)), None) => {
+                    let variant_name = &variant.ident;
 
-                            quote::quote! { Self::#variant_name(#(#fields,)*) }
-                        }
-                        Fields::Unit => quote::quote! { Self::#variant_name },
-                    }
-                } else {
+                    (
+                        construct(&default, quote::quote!(Self::#variant_name), &variant.fields)?,
+                        default_field_types(&variant.fields),
+                    )
+                }
+                (Some((_, first)), Some((_, second))) => {
+                    return Err(duplicate_default_error(
+                        first,
+                        second,
+                        "Use a single `#[default]` attribute to mark a variant as the default one.",
+                    ))
+                }
+                (None, _) => {
                     return Err(syn::Error::new(
                         span,
                         "Use a single `#[default]` attribute to mark a variant as the default one.",
-                    ));
+                    ))
                 }
             }
-            Data::Union(_) => quote::quote!(*self),
-        },
-    ))
+        }
+        Data::Union(data_union) => {
+            let mut default_fields_iter = data_union.fields.named.iter().filter_map(|field| {
+                field
+                    .attrs
+                    .iter()
+                    .find(|attr| is_default_attribute(attr))
+                    .map(|attr| (field, attr))
+            });
+
+            match (default_fields_iter.next(), default_fields_iter.next()) {
+                (Some((field, _)), None) => {
+                    let field_name = field.ident.as_ref().unwrap();
+                    let attrs = field
+                        .attrs
+                        .iter()
+                        .filter(|attr| is_default_attribute(attr))
+                        .collect::<Vec<_>>();
+
+                    // A bare `#[default]` initializes the field with its own default value.
+                    let (value, field_types) = if matches!(attrs.as_slice(), [attr] if attr.tokens.is_empty()) {
+                        (default.clone(), vec![&field.ty])
+                    } else {
+                        (field_default(&default, field)?, Vec::new())
+                    };
+
+                    (quote::quote! { Self { #field_name: #value } }, field_types)
+                }
+                (Some((_, first)), Some((_, second))) => {
+                    return Err(duplicate_default_error(
+                        first,
+                        second,
+                        "Use a single `#[default]` attribute to mark the field a `union` is initialized with.",
+                    ))
+                }
+                (None, _) => {
+                    return Err(syn::Error::new(
+                        data_union.union_token.span,
+                        "Use a single `#[default]` attribute to mark the field a `union` is initialized with.",
+                    ))
+                }
+            }
+        }
+    };
+
+    let generics = attributes::apply_bounds(
+        input.generics,
+        &input.attrs,
+        "Default",
+        quote::quote!(::core::default::Default),
+        field_types,
+    )?;
+
+    Ok(derive_with(input.ident, generics, body))
 }
 
 #[cfg(test)]
@@ -482,4 +608,260 @@ mod tests {
             assert!(super::derive_default(utilities::parse_derive_input(input).unwrap()).is_err());
         }
     }
+
+    #[test]
+    fn test_derive_default_field_expression() {
+        let test_cases = [
+            // Struct.
+            (
+                quote::quote! {
+                    struct Foo<T> {
+                        #[default = 8080]
+                        port: u16,
+                        #[default(String::from("localhost"))]
+                        host: String,
+                        items: Vec<T>,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::default::Default for Foo<T> {
+                        fn default() -> Self {
+                            Self {
+                                port: 8080,
+                                host: String::from("localhost"),
+                                items: ::core::default::Default::default(),
+                            }
+                        }
+                    }
+                },
+            ),
+            // Tuple.
+            (
+                quote::quote! {
+                    struct Foo<T>(#[default(1 + 2)] u32, PhantomData<T>);
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::default::Default for Foo<T> {
+                        fn default() -> Self {
+                            Self(1 + 2, ::core::default::Default::default(),)
+                        }
+                    }
+                },
+            ),
+            // Enum.
+            (
+                quote::quote! {
+                    enum Foo<T> {
+                        Tuple(#[default = 3] u32),
+                        #[default]
+                        Struct {
+                            #[default(vec![1, 2])]
+                            foo: Vec<u32>,
+                            bar: PhantomData<T>,
+                        },
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::default::Default for Foo<T> {
+                        fn default() -> Self {
+                            Self::Struct {
+                                foo: vec![1, 2],
+                                bar: ::core::default::Default::default(),
+                            }
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_default(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_default_perfect() {
+        let input = quote::quote! {
+            #[force_derive(perfect)]
+            enum Foo<T> {
+                Tuple(Vec<T>),
+                #[default]
+                Struct {
+                    foo: Option<T>,
+                    #[default(None)]
+                    bar: Option<Box<T>>,
+                },
+            }
+        };
+
+        let expected = quote::quote! {
+            #[automatically_derived]
+            impl<T> ::core::default::Default for Foo<T>
+            where
+                Option<T>: ::core::default::Default
+            {
+                fn default() -> Self {
+                    Self::Struct {
+                        foo: ::core::default::Default::default(),
+                        bar: None,
+                    }
+                }
+            }
+        };
+
+        assert_eq!(
+            super::derive_default(utilities::parse_derive_input(input).unwrap())
+                .unwrap()
+                .to_string(),
+            expected.to_string(),
+        );
+    }
+
+    #[test]
+    fn test_derive_default_union() {
+        let test_cases = [
+            // Bare `#[default]`.
+            (
+                quote::quote! {
+                    union Foo<T: Copy> {
+                        foo: u32,
+                        #[default]
+                        bar: T,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T: Copy> ::core::default::Default for Foo<T> {
+                        fn default() -> Self {
+                            Self {
+                                bar: ::core::default::Default::default()
+                            }
+                        }
+                    }
+                },
+            ),
+            // Field expression.
+            (
+                quote::quote! {
+                    union Foo {
+                        #[default(1 + 2)]
+                        foo: u32,
+                        bar: f32,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::default::Default for Foo {
+                        fn default() -> Self {
+                            Self { foo: 1 + 2 }
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_default(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_default_wrong_union_attribute() {
+        let test_cases = [
+            quote::quote! {
+                union Foo {
+                    foo: u32,
+                    bar: f32,
+                }
+            },
+            quote::quote! {
+                union Foo {
+                    #[default]
+                    foo: u32,
+                    #[default]
+                    bar: f32,
+                }
+            },
+            quote::quote! {
+                union Foo {
+                    #[default]
+                    #[default(2)]
+                    foo: u32,
+                }
+            },
+        ];
+
+        for input in test_cases {
+            assert!(super::derive_default(utilities::parse_derive_input(input).unwrap()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_derive_default_wrong_field_attribute() {
+        let test_cases = [
+            quote::quote! {
+                struct Foo {
+                    #[default]
+                    foo: u32,
+                }
+            },
+            quote::quote! {
+                struct Foo {
+                    #[default(1, 2)]
+                    foo: u32,
+                }
+            },
+            quote::quote! {
+                struct Foo {
+                    #[default = ]
+                    foo: u32,
+                }
+            },
+            quote::quote! {
+                struct Foo(#[default(1)] #[default(2)] u32);
+            },
+            quote::quote! {
+                enum Foo {
+                    #[default]
+                    A(#[default] u32),
+                }
+            },
+        ];
+
+        for input in test_cases {
+            assert!(super::derive_default(utilities::parse_derive_input(input).unwrap()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_derive_default_unsized() {
+        let test_cases = [
+            quote::quote! {
+                struct Foo(u32, str);
+            },
+            quote::quote! {
+                struct Foo<H: ?Sized> {
+                    len: u32,
+                    header: H,
+                }
+            },
+        ];
+
+        for input in test_cases {
+            assert!(super::derive_default(utilities::parse_derive_input(input).unwrap()).is_err());
+        }
+    }
 }
@@ -0,0 +1,404 @@
+use crate::{attributes, utilities};
+use proc_macro2::{Span, TokenStream};
+use syn::{Attribute, Data, DeriveInput, Fields, Generics, Ident, LitStr};
+
+/// Parses the `#[display("...")]` format string of a type or an enum variant.
+fn parse_format(attrs: &[Attribute]) -> syn::Result<Option<LitStr>> {
+    let mut result = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("display")) {
+        result = Some(attr.parse_args()?);
+    }
+
+    Ok(result)
+}
+
+/// Returns the arguments referenced by `format`, including the ones used as width or precision, like `width` in
+/// `{foo:>width$}`. Positional arguments are returned as their indices, or as empty strings if they are implicit.
+fn get_referenced_names(format: &str) -> Vec<String> {
+    fn push_name(result: &mut Vec<String>, name: &str) {
+        if !result.iter().any(|item| item == name) {
+            result.push(name.to_string());
+        }
+    }
+
+    let mut result = Vec::new();
+    let mut chars = format.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '{' && chars.next_if_eq(&'{').is_none() {
+            let mut argument = String::new();
+
+            while let Some(c) = chars.next_if(|c| *c != '}') {
+                argument.push(c);
+            }
+
+            let (name, spec) = argument.split_once(':').unwrap_or((&argument, ""));
+
+            push_name(&mut result, name.trim());
+
+            if let Some((counts, _)) = spec.rsplit_once('$') {
+                for count in counts.split('$') {
+                    let start = count
+                        .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+                        .map_or(0, |i| i + 1);
+
+                    push_name(&mut result, &count[start..]);
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn derive_with(ty: Ident, generics: Generics, body: TokenStream) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let f = utilities::get_local_variable("f");
+
+    quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Display for #ty #type_generics
+        #where_clause
+        {
+            fn fmt(&self, #f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                #body
+            }
+        }
+    }
+}
+
+fn write_str(name: &str) -> TokenStream {
+    let f = utilities::get_local_variable("f");
+
+    quote::quote!(#f.write_str(#name))
+}
+
+/// Generates the expression that writes `format`. `get_reference` returns the expression of a reference to the named
+/// field, which is used as the value of the format argument with the same name.
+fn write_format(
+    format: &LitStr,
+    fields: &Fields,
+    get_reference: impl Fn(&Ident) -> TokenStream,
+) -> syn::Result<TokenStream> {
+    let f = utilities::get_local_variable("f");
+    let format_string = format.value();
+    let names = get_referenced_names(&format_string);
+
+    if names.is_empty() && !format_string.contains(['{', '}']) {
+        return Ok(write_str(&format_string));
+    }
+
+    let arguments = names
+        .iter()
+        .map(|name| {
+            if !name.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+                return Err(syn::Error::new_spanned(
+                    format,
+                    "Refer to fields by name in `display` format strings.",
+                ));
+            }
+
+            let field = fields
+                .iter()
+                .filter_map(|field| field.ident.as_ref())
+                .find(|ident| *ident == name)
+                .ok_or_else(|| {
+                    syn::Error::new_spanned(format, format!("Unknown field `{}` in `display` format string.", name))
+                })?;
+
+            let reference = get_reference(field);
+
+            Ok(quote::quote!(#field = #reference))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote::quote!(::core::write!(#f, #format #(, #arguments)*)))
+}
+
+/// Generates the expression that displays a struct or an enum variant named `name`. Without a format string, only
+/// types and variants without fields are supported, which are displayed as their names.
+fn display_fields(
+    name: &Ident,
+    format: Option<&LitStr>,
+    fields: &Fields,
+    get_reference: impl Fn(&Ident) -> TokenStream,
+) -> syn::Result<TokenStream> {
+    match format {
+        Some(format) => write_format(format, fields, get_reference),
+        None if fields.is_empty() => Ok(write_str(&name.to_string())),
+        None => Err(syn::Error::new_spanned(
+            name,
+            "Use `#[display(\"...\")]` to specify a format string for a type or variant with fields.",
+        )),
+    }
+}
+
+pub fn derive_display(input: DeriveInput) -> syn::Result<TokenStream> {
+    // The traits required by the fields depend on the format string, so no field bounds are generated, even with
+    // `#[force_derive(perfect)]`.
+    let generics = attributes::apply_bounds(
+        input.generics,
+        &input.attrs,
+        "Display",
+        quote::quote!(::core::fmt::Display),
+        [],
+    )?;
+
+    let format = parse_format(&input.attrs)?;
+
+    let body = match &input.data {
+        Data::Struct(data_struct) => display_fields(
+            &input.ident,
+            format.as_ref(),
+            &data_struct.fields,
+            |field| quote::quote!(&self.#field),
+        )?,
+        Data::Enum(data_enum) => {
+            if let Some(format) = &format {
+                return Err(syn::Error::new_spanned(
+                    format,
+                    "Use `#[display(\"...\")]` on enum variants instead.",
+                ));
+            }
+
+            let variants = &data_enum.variants;
+
+            if variants.is_empty() {
+                quote::quote! { match *self {} }
+            } else {
+                let arms = variants
+                    .iter()
+                    .map(|variant| {
+                        let variant_name = &variant.ident;
+                        let format = parse_format(&variant.attrs)?;
+
+                        let get_variable =
+                            |field: &Ident| quote::format_ident!("field_{}", field, span = Span::mixed_site());
+
+                        let body = display_fields(variant_name, format.as_ref(), &variant.fields, |field| {
+                            let variable = get_variable(field);
+
+                            quote::quote!(#variable)
+                        })?;
+
+                        // Only the fields referenced by the format string are bound.
+                        let names = format
+                            .as_ref()
+                            .map(|format| get_referenced_names(&format.value()))
+                            .unwrap_or_default();
+
+                        let pattern_fields = variant
+                            .fields
+                            .iter()
+                            .filter_map(|field| field.ident.as_ref())
+                            .filter(|ident| names.iter().any(|name| *ident == name))
+                            .collect::<Vec<_>>();
+
+                        let field_variables = pattern_fields.iter().map(|field| get_variable(field));
+
+                        Ok(match &variant.fields {
+                            Fields::Named(_) => quote::quote! {
+                                Self::#variant_name { #(#pattern_fields: #field_variables,)* .. } => #body
+                            },
+                            Fields::Unnamed(_) => quote::quote! {
+                                Self::#variant_name(..) => #body
+                            },
+                            Fields::Unit => quote::quote! {
+                                Self::#variant_name => #body
+                            },
+                        })
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
+
+                quote::quote! {
+                    match self {
+                        #(#arms,)*
+                    }
+                }
+            }
+        }
+        Data::Union(data_union) => {
+            return Err(syn::Error::new(
+                data_union.union_token.span,
+                "Cannot derive `Display` on a `union`.",
+            ))
+        }
+    };
+
+    Ok(derive_with(input.ident, generics, body))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utilities;
+
+    #[test]
+    fn test_get_referenced_names() {
+        let test_cases: [(&str, &[&str]); 6] = [
+            ("foo", &[]),
+            ("{{foo}}", &[]),
+            ("{foo} {bar:?} {foo}", &["foo", "bar"]),
+            ("{} {0}", &["", "0"]),
+            ("{foo:>width$.precision$}", &["foo", "width", "precision"]),
+            ("{foo:.1$}", &["foo", "1"]),
+        ];
+
+        for (format, expected) in test_cases {
+            assert_eq!(super::get_referenced_names(format), expected);
+        }
+    }
+
+    #[test]
+    fn test_derive_display() {
+        let test_cases = [
+            // Unit.
+            (
+                quote::quote! {
+                    struct Foo;
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::fmt::Display for Foo {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            f.write_str("Foo")
+                        }
+                    }
+                },
+            ),
+            // Struct with a format string.
+            (
+                quote::quote! {
+                    #[display("{foo}:{bar:?}")]
+                    struct Foo<T> {
+                        foo: u32,
+                        bar: u32,
+                        baz: PhantomData<T>,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::fmt::Display for Foo<T> {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            ::core::write!(f, "{foo}:{bar:?}", foo = &self.foo, bar = &self.bar)
+                        }
+                    }
+                },
+            ),
+            // Struct with a format string without arguments.
+            (
+                quote::quote! {
+                    #[display("foo")]
+                    struct Foo<T>(PhantomData<T>);
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::fmt::Display for Foo<T> {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            f.write_str("foo")
+                        }
+                    }
+                },
+            ),
+            // Empty enum.
+            (
+                quote::quote! {
+                    enum Foo {}
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::fmt::Display for Foo {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            match *self {}
+                        }
+                    }
+                },
+            ),
+            // Enum.
+            (
+                quote::quote! {
+                    #[force_derive(bound(Display = "T: Display"))]
+                    enum Foo<T> {
+                        Unit,
+                        #[display("other")]
+                        Other,
+                        #[display("{foo}")]
+                        Struct { foo: T, bar: u32 },
+                        #[display("{{tuple}}")]
+                        Tuple(T),
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::fmt::Display for Foo<T>
+                    where
+                        T: Display
+                    {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            match self {
+                                Self::Unit => f.write_str("Unit"),
+                                Self::Other => f.write_str("other"),
+                                Self::Struct { foo: field_foo, .. } => ::core::write!(f, "{foo}", foo = field_foo),
+                                Self::Tuple(..) => ::core::write!(f, "{{tuple}}"),
+                            }
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_display(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_display_wrong_attributes() {
+        let test_cases = [
+            quote::quote! {
+                struct Foo {
+                    foo: u32,
+                }
+            },
+            quote::quote! {
+                #[display("{bar}")]
+                struct Foo {
+                    foo: u32,
+                }
+            },
+            quote::quote! {
+                #[display("{0}")]
+                struct Foo(u32);
+            },
+            quote::quote! {
+                #[display(foo)]
+                struct Foo;
+            },
+            quote::quote! {
+                #[display("Foo")]
+                enum Foo {
+                    Unit,
+                }
+            },
+            quote::quote! {
+                enum Foo {
+                    Tuple(u32),
+                }
+            },
+            quote::quote! {
+                union Foo {
+                    foo: u32,
+                }
+            },
+        ];
+
+        for input in test_cases {
+            assert!(super::derive_display(utilities::parse_derive_input(input).unwrap()).is_err());
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use proc_macro2::TokenStream;
 use syn::DeriveInput;
 
-pub fn derive_eq(input: DeriveInput) -> TokenStream {
-    crate::marker_trait::derive_marker_trait(input, quote::quote!(::core::cmp::Eq))
+pub fn derive_eq(input: DeriveInput) -> syn::Result<TokenStream> {
+    crate::marker_trait::derive_marker_trait(input, "Eq", quote::quote!(::core::cmp::Eq))
 }
 
 #[cfg(test)]
@@ -151,9 +151,43 @@ mod tests {
 
         for (input, expected) in test_cases {
             assert_eq!(
-                super::derive_eq(utilities::parse_derive_input(input).unwrap()).to_string(),
+                super::derive_eq(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
                 expected.to_string()
             );
         }
     }
+
+    #[test]
+    fn test_derive_eq_perfect() {
+        let input = quote::quote! {
+            #[force_derive(perfect)]
+            struct Foo<T> {
+                foo: PhantomData<T>,
+                #[force_derive(skip)]
+                bar: Cache<T>,
+                #[partial_eq(with = approx_eq)]
+                baz: Vec<T>,
+                #[force_derive(key = "Vec::len")]
+                qux: Vec<T>,
+            }
+        };
+
+        let expected = quote::quote! {
+            #[automatically_derived]
+            impl<T> ::core::cmp::Eq for Foo<T>
+            where
+                PhantomData<T>: ::core::cmp::Eq
+            {
+            }
+        };
+
+        assert_eq!(
+            super::derive_eq(utilities::parse_derive_input(input).unwrap())
+                .unwrap()
+                .to_string(),
+            expected.to_string()
+        );
+    }
 }
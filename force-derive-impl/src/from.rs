@@ -0,0 +1,582 @@
+use crate::{attributes, utilities};
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use std::collections::HashSet;
+use syn::{
+    AngleBracketedGenericArguments, Attribute, Data, DeriveInput, Expr, Field, Fields, GenericArgument, Generics,
+    Ident, Lit, Meta, PathArguments, Type, WherePredicate,
+};
+
+fn derive_with(
+    ty: &Ident,
+    mut generics: Generics,
+    source: &Type,
+    default_types: &[&Type],
+    body: TokenStream,
+) -> TokenStream {
+    let value = utilities::get_local_variable("value");
+
+    if !default_types.is_empty() {
+        generics.make_where_clause().predicates.extend(
+            default_types
+                .iter()
+                .map(|ty| -> WherePredicate { syn::parse_quote!(#ty: ::core::default::Default) }),
+        );
+    }
+
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<#source> for #ty #type_generics
+        #where_clause
+        {
+            fn from(#value: #source) -> Self {
+                #body
+            }
+        }
+    }
+}
+
+fn is_from_attribute(attr: &Attribute) -> bool {
+    attr.path.is_ident("from")
+}
+
+/// Returns whether `field` is marked with `#[from(default)]`.
+fn is_default_field(field: &Field) -> syn::Result<bool> {
+    let mut result = false;
+
+    for attr in field.attrs.iter().filter(|attr| is_from_attribute(attr)) {
+        let argument = attr.parse_args::<Ident>()?;
+
+        if argument == "default" {
+            result = true;
+        } else {
+            return Err(syn::Error::new_spanned(argument, "Unknown `from` field argument."));
+        }
+    }
+
+    Ok(result)
+}
+
+/// Returns whether `variant` is marked with `#[from]`.
+fn is_from_variant(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut result = false;
+
+    for attr in attrs.iter().filter(|attr| is_from_attribute(attr)) {
+        if let Meta::Path(_) = attr.parse_meta()? {
+            result = true;
+        } else {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "Use `#[from]` without arguments on enum variants.",
+            ));
+        }
+    }
+
+    Ok(result)
+}
+
+/// A `From` implementation for a struct or an enum variant.
+struct Conversion<'a> {
+    source: &'a Type,
+    default_types: Vec<&'a Type>,
+    body: TokenStream,
+}
+
+/// Generates the conversion into the struct or enum variant at `path` named `name`. The source type is the type of the
+/// only field that is neither `PhantomData` nor marked with `#[from(default)]`, other fields are filled with
+/// `PhantomData` or their default values.
+fn conversion<'a>(name: &Ident, path: TokenStream, fields: &'a Fields) -> syn::Result<Conversion<'a>> {
+    let value = utilities::get_local_variable("value");
+    let mut source = None;
+    let mut default_types = Vec::new();
+    let mut values = Vec::new();
+
+    for field in fields {
+        values.push(if is_default_field(field)? {
+            default_types.push(&field.ty);
+
+            quote::quote!(::core::default::Default::default())
+        } else if utilities::is_phantom_data(&field.ty) {
+            quote::quote!(::core::marker::PhantomData)
+        } else if source.replace(&field.ty).is_none() {
+            quote::quote!(#value)
+        } else {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "Deriving `From` requires exactly one field that is not `PhantomData` or marked with \
+                 `#[from(default)]`.",
+            ));
+        });
+    }
+
+    let source = source.ok_or_else(|| {
+        syn::Error::new_spanned(
+            name,
+            "Deriving `From` requires exactly one field that is not `PhantomData` or marked with `#[from(default)]`.",
+        )
+    })?;
+
+    let body = match fields {
+        Fields::Named(fields) => {
+            let fields = fields.named.iter().map(|field| field.ident.as_ref().unwrap());
+
+            quote::quote!(#path { #(#fields: #values,)* })
+        }
+        Fields::Unnamed(_) => quote::quote!(#path(#(#values,)*)),
+        Fields::Unit => unreachable!(),
+    };
+
+    Ok(Conversion {
+        source,
+        default_types,
+        body,
+    })
+}
+
+/// Returns whether `ty` may be any type, like a type parameter, an associated type or a type macro.
+fn is_any_type(ty: &Type, type_parameters: &HashSet<&Ident>) -> bool {
+    match ty {
+        Type::Group(type_group) => is_any_type(&type_group.elem, type_parameters),
+        Type::Paren(type_paren) => is_any_type(&type_paren.elem, type_parameters),
+        Type::Path(type_path) => {
+            type_path.qself.is_some()
+                || (type_path.path.leading_colon.is_none()
+                    && type_path
+                        .path
+                        .segments
+                        .first()
+                        .is_some_and(|segment| type_parameters.contains(&segment.ident)))
+        }
+        Type::Macro(_) => true,
+        _ => false,
+    }
+}
+
+fn get_type_arguments(arguments: &AngleBracketedGenericArguments) -> impl Iterator<Item = &Type> {
+    arguments.args.iter().filter_map(|argument| match argument {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    })
+}
+
+/// Returns whether `lhs` and `rhs` may be the same type for some type arguments, in which case their `From`
+/// implementations would conflict. Paths are compared as written and type aliases are not resolved, so different
+/// spellings of the same type are still reported by the compiler.
+fn may_overlap(lhs: &Type, rhs: &Type, type_parameters: &HashSet<&Ident>) -> bool {
+    if is_any_type(lhs, type_parameters) || is_any_type(rhs, type_parameters) {
+        return true;
+    }
+
+    match (lhs, rhs) {
+        (Type::Group(lhs), _) => may_overlap(&lhs.elem, rhs, type_parameters),
+        (_, Type::Group(rhs)) => may_overlap(lhs, &rhs.elem, type_parameters),
+        (Type::Paren(lhs), _) => may_overlap(&lhs.elem, rhs, type_parameters),
+        (_, Type::Paren(rhs)) => may_overlap(lhs, &rhs.elem, type_parameters),
+        (Type::Array(lhs), Type::Array(rhs)) => {
+            let same_length = match (&lhs.len, &rhs.len) {
+                (Expr::Lit(lhs), Expr::Lit(rhs)) => match (&lhs.lit, &rhs.lit) {
+                    (Lit::Int(lhs), Lit::Int(rhs)) => lhs.base10_digits() == rhs.base10_digits(),
+                    _ => true,
+                },
+                _ => true,
+            };
+
+            same_length && may_overlap(&lhs.elem, &rhs.elem, type_parameters)
+        }
+        (Type::Ptr(lhs), Type::Ptr(rhs)) => {
+            lhs.mutability.is_some() == rhs.mutability.is_some() && may_overlap(&lhs.elem, &rhs.elem, type_parameters)
+        }
+        (Type::Reference(lhs), Type::Reference(rhs)) => {
+            lhs.mutability.is_some() == rhs.mutability.is_some() && may_overlap(&lhs.elem, &rhs.elem, type_parameters)
+        }
+        (Type::Slice(lhs), Type::Slice(rhs)) => may_overlap(&lhs.elem, &rhs.elem, type_parameters),
+        (Type::Tuple(lhs), Type::Tuple(rhs)) => {
+            lhs.elems.len() == rhs.elems.len()
+                && lhs
+                    .elems
+                    .iter()
+                    .zip(&rhs.elems)
+                    .all(|(lhs, rhs)| may_overlap(lhs, rhs, type_parameters))
+        }
+        (Type::Path(lhs), Type::Path(rhs)) if lhs.qself.is_none() && rhs.qself.is_none() => {
+            lhs.path.leading_colon.is_some() == rhs.path.leading_colon.is_some()
+                && lhs.path.segments.len() == rhs.path.segments.len()
+                && lhs.path.segments.iter().zip(&rhs.path.segments).all(|(lhs, rhs)| {
+                    lhs.ident == rhs.ident
+                        && match (&lhs.arguments, &rhs.arguments) {
+                            (PathArguments::None, PathArguments::None) => true,
+                            (PathArguments::AngleBracketed(lhs), PathArguments::AngleBracketed(rhs)) => {
+                                get_type_arguments(lhs)
+                                    .zip(get_type_arguments(rhs))
+                                    .all(|(lhs, rhs)| may_overlap(lhs, rhs, type_parameters))
+                            }
+                            _ => true,
+                        }
+                })
+        }
+        (
+            Type::Array(_) | Type::Ptr(_) | Type::Reference(_) | Type::Slice(_) | Type::Tuple(_) | Type::Path(_),
+            Type::Array(_) | Type::Ptr(_) | Type::Reference(_) | Type::Slice(_) | Type::Tuple(_) | Type::Path(_),
+        ) => false,
+        _ => lhs.to_token_stream().to_string() == rhs.to_token_stream().to_string(),
+    }
+}
+
+pub fn derive_from(input: DeriveInput) -> syn::Result<TokenStream> {
+    // Source types are not required to implement anything, so only the bounds given by the user are applied.
+    let generics = attributes::apply_bounds(
+        input.generics.clone(),
+        &input.attrs,
+        "From",
+        quote::quote!(::core::convert::From),
+        [],
+    )?;
+
+    let ty = &input.ident;
+
+    match &input.data {
+        Data::Struct(data_struct) => {
+            let conversion = conversion(ty, quote::quote!(Self), &data_struct.fields)?;
+
+            Ok(derive_with(
+                ty,
+                generics,
+                conversion.source,
+                &conversion.default_types,
+                conversion.body,
+            ))
+        }
+        Data::Enum(data_enum) => {
+            let type_parameters = input
+                .generics
+                .type_params()
+                .map(|param| &param.ident)
+                .collect::<HashSet<_>>();
+            let mut sources = Vec::<&Type>::new();
+            let mut result = TokenStream::new();
+
+            for variant in &data_enum.variants {
+                if is_from_variant(&variant.attrs)? {
+                    let variant_name = &variant.ident;
+                    let conversion = conversion(variant_name, quote::quote!(Self::#variant_name), &variant.fields)?;
+
+                    if let Some(first) = sources
+                        .iter()
+                        .find(|first| may_overlap(first, conversion.source, &type_parameters))
+                    {
+                        let error = if first.to_token_stream().to_string()
+                            == conversion.source.to_token_stream().to_string()
+                        {
+                            let mut error = syn::Error::new_spanned(
+                                conversion.source,
+                                "Conflicting `From` implementations, another `#[from]` variant has the same field type.",
+                            );
+
+                            error.combine(syn::Error::new_spanned(
+                                first,
+                                "First `#[from]` variant with this type here.",
+                            ));
+
+                            error
+                        } else {
+                            let mut error = syn::Error::new_spanned(
+                                conversion.source,
+                                "Conflicting `From` implementations, another `#[from]` variant has a field type that \
+                                 may be the same type for some type arguments.",
+                            );
+
+                            error.combine(syn::Error::new_spanned(
+                                first,
+                                "First `#[from]` variant with an overlapping type here.",
+                            ));
+
+                            error
+                        };
+
+                        return Err(error);
+                    }
+
+                    sources.push(conversion.source);
+
+                    result.extend(derive_with(
+                        ty,
+                        generics.clone(),
+                        conversion.source,
+                        &conversion.default_types,
+                        conversion.body,
+                    ));
+                }
+            }
+
+            if sources.is_empty() {
+                Err(syn::Error::new_spanned(
+                    ty,
+                    "Mark the enum variants to convert from with `#[from]`.",
+                ))
+            } else {
+                Ok(result)
+            }
+        }
+        Data::Union(data_union) => Err(syn::Error::new(
+            data_union.union_token.span,
+            "Cannot derive `From` on a `union`.",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utilities;
+
+    #[test]
+    fn test_derive_from() {
+        let test_cases = [
+            // Tuple.
+            (
+                quote::quote! {
+                    struct Meters<T>(f64, PhantomData<T>);
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::convert::From<f64> for Meters<T> {
+                        fn from(value: f64) -> Self {
+                            Self(value, ::core::marker::PhantomData,)
+                        }
+                    }
+                },
+            ),
+            // Struct with a default field and generic constraints.
+            (
+                quote::quote! {
+                    struct Foo<T>
+                    where
+                        u32: Copy,
+                    {
+                        foo: PhantomData<T>,
+                        bar: Vec<T>,
+                        #[from(default)]
+                        baz: T,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::convert::From<Vec<T> > for Foo<T>
+                    where
+                        u32: Copy,
+                        T: ::core::default::Default
+                    {
+                        fn from(value: Vec<T>) -> Self {
+                            Self {
+                                foo: ::core::marker::PhantomData,
+                                bar: value,
+                                baz: ::core::default::Default::default(),
+                            }
+                        }
+                    }
+                },
+            ),
+            // Enum.
+            (
+                quote::quote! {
+                    enum Foo<T> {
+                        #[from]
+                        Tuple(u32),
+                        #[from]
+                        Struct { foo: String, bar: PhantomData<T> },
+                        Other(u32),
+                        Unit,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::convert::From<u32> for Foo<T> {
+                        fn from(value: u32) -> Self {
+                            Self::Tuple(value,)
+                        }
+                    }
+
+                    #[automatically_derived]
+                    impl<T> ::core::convert::From<String> for Foo<T> {
+                        fn from(value: String) -> Self {
+                            Self::Struct {
+                                foo: value,
+                                bar: ::core::marker::PhantomData,
+                            }
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_from(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_from_wrong_attributes() {
+        let test_cases = [
+            quote::quote! {
+                struct Foo;
+            },
+            quote::quote! {
+                struct Foo<T>(PhantomData<T>);
+            },
+            quote::quote! {
+                struct Foo(u32, u32);
+            },
+            quote::quote! {
+                struct Foo(#[from(foo)] u32);
+            },
+            quote::quote! {
+                enum Foo {
+                    Tuple(u32),
+                }
+            },
+            quote::quote! {
+                enum Foo {
+                    #[from(foo)]
+                    Tuple(u32),
+                }
+            },
+            quote::quote! {
+                enum Foo {
+                    #[from]
+                    Unit,
+                }
+            },
+            quote::quote! {
+                enum Foo {
+                    #[from]
+                    Foo(u32),
+                    #[from]
+                    Bar(u32),
+                }
+            },
+            quote::quote! {
+                union Foo {
+                    foo: u32,
+                }
+            },
+        ];
+
+        for input in test_cases {
+            assert!(super::derive_from(utilities::parse_derive_input(input).unwrap()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_derive_from_overlapping_variants() {
+        let test_cases = [
+            (
+                quote::quote! {
+                    enum Foo<T> {
+                        #[from]
+                        A(Vec<T>),
+                        #[from]
+                        B(Option<T>),
+                        #[from]
+                        C(&'static str),
+                        #[from]
+                        D(&'static mut str),
+                        #[from]
+                        E((u32, T)),
+                        #[from]
+                        F([T; 2]),
+                        #[from]
+                        G(u32),
+                        #[from]
+                        H(std::io::Error),
+                        #[from]
+                        I(std::fmt::Error),
+                        #[from]
+                        J([u8; 4]),
+                        #[from]
+                        K([u8; 8]),
+                    }
+                },
+                true,
+            ),
+            (
+                quote::quote! {
+                    enum Foo<T, U> {
+                        #[from]
+                        A(T),
+                        #[from]
+                        B(U),
+                    }
+                },
+                false,
+            ),
+            (
+                quote::quote! {
+                    enum Foo<T> {
+                        #[from]
+                        A(u32),
+                        #[from]
+                        B(T),
+                    }
+                },
+                false,
+            ),
+            (
+                quote::quote! {
+                    enum Foo<T> {
+                        #[from]
+                        A(Vec<T>),
+                        #[from]
+                        B(Vec<u32>),
+                    }
+                },
+                false,
+            ),
+            (
+                quote::quote! {
+                    enum Foo<T: Iterator> {
+                        #[from]
+                        A(T::Item),
+                        #[from]
+                        B(u32),
+                    }
+                },
+                false,
+            ),
+            (
+                quote::quote! {
+                    enum Foo<T, U> {
+                        #[from]
+                        A((T, u32)),
+                        #[from]
+                        B((u64, U)),
+                    }
+                },
+                false,
+            ),
+            (
+                quote::quote! {
+                    enum Foo {
+                        #[from]
+                        A([u8; 4]),
+                        #[from]
+                        B([u8; 0x4]),
+                    }
+                },
+                false,
+            ),
+        ];
+
+        for (input, ok) in test_cases {
+            assert_eq!(
+                super::derive_from(utilities::parse_derive_input(input).unwrap()).is_ok(),
+                ok
+            );
+        }
+    }
+}
@@ -1,80 +1,124 @@
-use crate::utilities;
-use proc_macro2::TokenStream;
-use syn::{Data, DeriveInput, Fields, Generics, Ident, Index, Variant};
+use crate::{attributes, utilities};
+use proc_macro2::{Span, TokenStream};
+use syn::{Data, DeriveInput, Field, Fields, Generics, Ident, Variant};
 
 fn derive_with(ty: Ident, generics: Generics, body: TokenStream) -> TokenStream {
     let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let state = utilities::get_local_variable("state");
 
     quote::quote! {
         #[automatically_derived]
         impl #impl_generics ::core::hash::Hash for #ty #type_generics
         #where_clause
         {
-            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+            fn hash<__H: ::core::hash::Hasher>(&self, #state: &mut __H) {
                 #body
             }
         }
     }
 }
 
-fn hash_variant(hash: &TokenStream, variant: &Variant) -> TokenStream {
+/// Generates the statement that hashes `field`, where `reference` is an expression of a reference to it. The field is
+/// hashed with the path given by `#[hash(with = path)]` if there is one, otherwise its key projection is hashed with
+/// `hash`.
+fn hash_field(hash: &TokenStream, field: &Field, reference: TokenStream) -> syn::Result<TokenStream> {
+    let state = utilities::get_local_variable("state");
+
+    Ok(match attributes::parse_with_argument(field, "hash")? {
+        Some(with) => quote::quote!(#with(#reference, #state);),
+        None => {
+            let reference = attributes::project_key(field, reference)?;
+
+            quote::quote!(#hash(#reference, #state);)
+        }
+    })
+}
+
+fn hash_variant(hash: &TokenStream, variant: &Variant) -> syn::Result<TokenStream> {
     let variant_name = &variant.ident;
+    let included_fields = attributes::included_fields(&variant.fields, "Hash")?;
 
-    match &variant.fields {
+    Ok(match &variant.fields {
         Fields::Named(fields) => {
-            let field_names = fields.named.iter().map(|field| field.ident.as_ref().unwrap());
+            let field_names = included_fields.iter().map(|(_, field)| field.ident.as_ref().unwrap());
 
             let field_variables = field_names
                 .clone()
-                .map(|field| quote::format_ident!("field_{}", field))
+                .map(|field| quote::format_ident!("field_{}", field, span = Span::mixed_site()))
                 .collect::<Vec<_>>();
 
+            let rest = utilities::get_rest_pattern(included_fields.len(), fields.named.len());
+
+            let statements = included_fields
+                .iter()
+                .zip(&field_variables)
+                .map(|((_, field), variable)| hash_field(hash, field, quote::quote!(#variable)))
+                .collect::<syn::Result<Vec<_>>>()?;
+
             quote::quote! {
-                Self::#variant_name { #(#field_names: #field_variables,)* } => {
-                    #(#hash(#field_variables, state);)*
+                Self::#variant_name { #(#field_names: #field_variables,)* #rest } => {
+                    #(#statements)*
                 }
             }
         }
         Fields::Unnamed(fields) => {
-            let fields = utilities::get_field_identifiers(fields.unnamed.len()).collect::<Vec<_>>();
+            let field_variables = utilities::get_field_identifiers(fields.unnamed.len())
+                .enumerate()
+                .filter(|(i, _)| included_fields.iter().any(|(included, _)| included == i))
+                .collect::<Vec<_>>();
+
+            let patterns = utilities::get_tuple_patterns(fields.unnamed.len(), &field_variables);
+
+            let statements = included_fields
+                .iter()
+                .zip(&field_variables)
+                .map(|((_, field), (_, variable))| hash_field(hash, field, quote::quote!(#variable)))
+                .collect::<syn::Result<Vec<_>>>()?;
 
             quote::quote! {
-                Self::#variant_name(#(#fields,)*) => {
-                    #(#hash(#fields, state);)*
+                Self::#variant_name(#(#patterns,)*) => {
+                    #(#statements)*
                 }
             }
         }
         Fields::Unit => quote::quote! { Self::#variant_name => {} },
-    }
+    })
 }
 
 pub fn derive_hash(input: DeriveInput) -> syn::Result<TokenStream> {
-    let span = input.ident.span();
     let hash = quote::quote!(::core::hash::Hash::hash);
+    let state = utilities::get_local_variable("state");
+
+    let generics = attributes::apply_bounds(
+        input.generics,
+        &input.attrs,
+        "Hash",
+        quote::quote!(::core::hash::Hash),
+        attributes::bounded_field_types(&input.data, "Hash", Some("hash"))?,
+    )?;
 
     Ok(derive_with(
         input.ident,
-        input.generics,
+        generics,
         match input.data {
-            Data::Struct(data_struct) => match data_struct.fields {
-                Fields::Named(fields) => {
-                    let fields = fields.named.into_iter().map(|field| field.ident.unwrap());
+            Data::Struct(data_struct) => {
+                let statements = attributes::included_fields(&data_struct.fields, "Hash")?
+                    .into_iter()
+                    .map(|(i, field)| {
+                        let member = utilities::get_member(i, field);
 
-                    quote::quote! { #(#hash(&self.#fields, state);)* }
-                }
-                Fields::Unnamed(fields) => {
-                    let fields = (0..fields.unnamed.len()).map(Index::from);
+                        hash_field(&hash, field, quote::quote!(&self.#member))
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
 
-                    quote::quote! { #(#hash(&self.#fields, state);)* }
-                }
-                Fields::Unit => quote::quote! {},
-            },
+                quote::quote! { #(#statements)* }
+            }
             Data::Enum(data_enum) => {
                 let variants = data_enum.variants;
 
                 if let Some(first) = variants.first() {
                     if variants.len() == 1 {
-                        let arm = hash_variant(&hash, first);
+                        let arm = hash_variant(&hash, first)?;
 
                         quote::quote! {
                             match self {
@@ -82,10 +126,13 @@ pub fn derive_hash(input: DeriveInput) -> syn::Result<TokenStream> {
                             }
                         }
                     } else {
-                        let arms = variants.iter().map(|variant| hash_variant(&hash, variant));
+                        let arms = variants
+                            .iter()
+                            .map(|variant| hash_variant(&hash, variant))
+                            .collect::<syn::Result<Vec<_>>>()?;
 
                         quote::quote! {
-                            #hash(&::core::mem::discriminant(self), state);
+                            #hash(&::core::mem::discriminant(self), #state);
 
                             match self {
                                 #(#arms,)*
@@ -96,7 +143,12 @@ pub fn derive_hash(input: DeriveInput) -> syn::Result<TokenStream> {
                     quote::quote!(match *self {})
                 }
             }
-            Data::Union(_) => return Err(syn::Error::new(span, "Cannot derive `Hash` on a `union`.")),
+            Data::Union(data_union) => {
+                return Err(syn::Error::new(
+                    data_union.union_token.span,
+                    "Cannot derive `Hash` on a `union`.",
+                ))
+            }
         },
     ))
 }
@@ -116,7 +168,7 @@ mod tests {
                 quote::quote! {
                     #[automatically_derived]
                     impl ::core::hash::Hash for Foo {
-                        fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {}
+                        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {}
                     }
                 },
             ),
@@ -130,7 +182,7 @@ mod tests {
                 quote::quote! {
                     #[automatically_derived]
                     impl<T> ::core::hash::Hash for Foo<T> {
-                        fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
                             ::core::hash::Hash::hash(&self.foo, state);
                         }
                     }
@@ -153,7 +205,7 @@ mod tests {
                     where
                         u32: Copy,
                     {
-                        fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
                             ::core::hash::Hash::hash(&self.foo, state);
                             ::core::hash::Hash::hash(&self.bar, state);
                         }
@@ -168,7 +220,7 @@ mod tests {
                 quote::quote! {
                     #[automatically_derived]
                     impl ::core::hash::Hash for Foo {
-                        fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {}
+                        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {}
                     }
                 },
             ),
@@ -180,7 +232,7 @@ mod tests {
                 quote::quote! {
                     #[automatically_derived]
                     impl<T> ::core::hash::Hash for Foo<T> {
-                        fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
                             ::core::hash::Hash::hash(&self.0, state);
                         }
                     }
@@ -199,7 +251,7 @@ mod tests {
                     where
                         u32: Copy
                     {
-                        fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
                             ::core::hash::Hash::hash(&self.0, state);
                             ::core::hash::Hash::hash(&self.1, state);
                         }
@@ -214,7 +266,7 @@ mod tests {
                 quote::quote! {
                     #[automatically_derived]
                     impl ::core::hash::Hash for Foo {
-                        fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {}
+                        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {}
                     }
                 },
             ),
@@ -226,7 +278,7 @@ mod tests {
                 quote::quote! {
                     #[automatically_derived]
                     impl ::core::hash::Hash for Foo {
-                        fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
                             match *self {}
                         }
                     }
@@ -242,7 +294,7 @@ mod tests {
                 quote::quote! {
                     #[automatically_derived]
                     impl<T> ::core::hash::Hash for Foo<T> {
-                        fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
                             match self {
                                 Self::Tuple1(field_0,) => {
                                     ::core::hash::Hash::hash(field_0, state);
@@ -274,7 +326,7 @@ mod tests {
                     where
                         u32: Copy,
                     {
-                        fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
                             ::core::hash::Hash::hash(&::core::mem::discriminant(self), state);
 
                             match self {
@@ -311,4 +363,251 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_derive_hash_skip() {
+        let test_cases = [
+            // Struct.
+            (
+                quote::quote! {
+                    struct Foo<T> {
+                        foo: u32,
+                        #[force_derive(skip)]
+                        bar: Cell<T>,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::hash::Hash for Foo<T> {
+                        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+                            ::core::hash::Hash::hash(&self.foo, state);
+                        }
+                    }
+                },
+            ),
+            // Tuple skipped only in `Hash`.
+            (
+                quote::quote! {
+                    struct Foo<T>(#[force_derive(skip(Hash))] Cell<T>, u32);
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::hash::Hash for Foo<T> {
+                        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+                            ::core::hash::Hash::hash(&self.1, state);
+                        }
+                    }
+                },
+            ),
+            // Enum.
+            (
+                quote::quote! {
+                    enum Foo<T> {
+                        Struct { #[force_derive(skip)] foo: Cell<T>, bar: u32 },
+                        Tuple(#[force_derive(skip(PartialEq, Hash))] Cell<T>, u32),
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::hash::Hash for Foo<T> {
+                        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+                            ::core::hash::Hash::hash(&::core::mem::discriminant(self), state);
+
+                            match self {
+                                Self::Struct { bar: field_bar, .. } => {
+                                    ::core::hash::Hash::hash(field_bar, state);
+                                },
+                                Self::Tuple(_, field_1,) => {
+                                    ::core::hash::Hash::hash(field_1, state);
+                                },
+                            }
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_hash(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_hash_with() {
+        let test_cases = [
+            // Struct.
+            (
+                quote::quote! {
+                    struct Foo<T> {
+                        foo: u32,
+                        #[hash(with = hash_bits)]
+                        bar: f64,
+                        #[force_derive(skip)]
+                        baz: Cell<T>,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::hash::Hash for Foo<T> {
+                        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+                            ::core::hash::Hash::hash(&self.foo, state);
+                            hash_bits(&self.bar, state);
+                        }
+                    }
+                },
+            ),
+            // Enum.
+            (
+                quote::quote! {
+                    enum Foo<T> {
+                        Struct { #[hash(with = ptr::hash)] foo: *const T },
+                        Tuple(u32, #[hash(with = hash_bits)] f64),
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::hash::Hash for Foo<T> {
+                        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+                            ::core::hash::Hash::hash(&::core::mem::discriminant(self), state);
+
+                            match self {
+                                Self::Struct { foo: field_foo, } => {
+                                    ptr::hash(field_foo, state);
+                                },
+                                Self::Tuple(field_0, field_1,) => {
+                                    ::core::hash::Hash::hash(field_0, state);
+                                    hash_bits(field_1, state);
+                                },
+                            }
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_hash(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_hash_skipped_in_partial_eq_only() {
+        let test_cases = [
+            quote::quote! {
+                struct Foo {
+                    #[force_derive(skip(PartialEq))]
+                    foo: u32,
+                }
+            },
+            quote::quote! {
+                enum Foo {
+                    Tuple(#[force_derive(skip(PartialEq))] u32),
+                }
+            },
+        ];
+
+        for input in test_cases {
+            assert!(super::derive_hash(utilities::parse_derive_input(input).unwrap()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_derive_hash_key() {
+        let test_cases = [(
+            quote::quote! {
+                struct Foo<T>(u32, #[force_derive(key = "Tagged::id")] Tagged<T>);
+            },
+            quote::quote! {
+                #[automatically_derived]
+                impl<T> ::core::hash::Hash for Foo<T> {
+                    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+                        ::core::hash::Hash::hash(&self.0, state);
+                        ::core::hash::Hash::hash(
+                            &{
+                                fn __force_derive_project_key<'a, T: ?::core::marker::Sized, K>(
+                                    value: &'a T,
+                                    key: impl ::core::ops::FnOnce(&'a T) -> K,
+                                ) -> K {
+                                    key(value)
+                                }
+
+                                __force_derive_project_key::<Tagged<T>, _>(&self.1, Tagged::id)
+                            },
+                            state
+                        );
+                    }
+                }
+            },
+        )];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_hash(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_hash_key_with_conflict() {
+        let input = quote::quote! {
+            struct Foo(#[force_derive(key = "f64::to_bits")] #[hash(with = hash_bits)] f64);
+        };
+
+        assert!(super::derive_hash(utilities::parse_derive_input(input).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_derive_hash_partial_eq_with() {
+        let test_cases = [
+            // Hashed with a custom function.
+            (
+                quote::quote! {
+                    struct Foo(#[partial_eq(with = approx_eq)] #[hash(with = hash_rounded)] f64);
+                },
+                true,
+            ),
+            // Skipped in `Hash`.
+            (
+                quote::quote! {
+                    struct Foo(#[partial_eq(with = approx_eq)] #[force_derive(skip(Hash))] f64);
+                },
+                true,
+            ),
+            // Hashed with the `Hash` implementation of the field type.
+            (
+                quote::quote! {
+                    struct Foo(#[partial_eq(with = approx_eq)] f64);
+                },
+                false,
+            ),
+            (
+                quote::quote! {
+                    enum Foo {
+                        Bar(#[partial_eq(with = approx_eq)] f64),
+                    }
+                },
+                false,
+            ),
+        ];
+
+        for (input, ok) in test_cases {
+            assert_eq!(
+                super::derive_hash(utilities::parse_derive_input(input).unwrap()).is_ok(),
+                ok
+            );
+        }
+    }
 }
@@ -7,36 +7,43 @@ use syn::DeriveInput;
 // - [x] Copy
 // - [x] Debug
 // - [x] Default
+// - [x] Display
 // - [x] Eq
+// - [x] From
 // - [x] Hash
-// - [ ] Ord
+// - [x] Ord
 // - [x] PartialEq
-// - [ ] PartialOrd
-// - Error span.
-// - Variable name conflict.
-// - `?Sized` field.
+// - [x] PartialOrd
+// - [x] Send
+// - [x] Sync
+// - [x] Unpin
+// - [x] Error span.
+// - [x] Variable name conflict.
+// - [x] `?Sized` field.
 
+mod attributes;
 mod clone;
 mod copy;
 mod debug;
 mod default;
+mod display;
 mod eq;
+mod from;
 mod hash;
 mod marker_trait;
+mod ord;
 mod partial_eq;
+mod partial_ord;
+mod send;
+mod sized;
+mod sync;
+mod unpin;
 mod utilities;
 
 fn parse_derive_input(input: TokenStream) -> Result<DeriveInput, TokenStream> {
     utilities::parse_derive_input(input.into()).map_err(|error| error.into())
 }
 
-fn derive_with(input: TokenStream, f: impl FnOnce(DeriveInput) -> proc_macro2::TokenStream) -> TokenStream {
-    match parse_derive_input(input) {
-        Ok(input) => f(input).into(),
-        Err(error) => error,
-    }
-}
-
 fn flatten_result(result: syn::Result<proc_macro2::TokenStream>) -> TokenStream {
     result.unwrap_or_else(syn::Error::into_compile_error).into()
 }
@@ -51,37 +58,72 @@ fn try_derive_with(
     }
 }
 
-#[proc_macro_derive(Clone)]
+#[proc_macro_derive(Clone, attributes(force_derive))]
 pub fn derive_clone(input: TokenStream) -> TokenStream {
-    derive_with(input, clone::derive_clone)
+    try_derive_with(input, clone::derive_clone)
 }
 
-#[proc_macro_derive(Copy)]
+#[proc_macro_derive(Copy, attributes(force_derive))]
 pub fn derive_copy(input: TokenStream) -> TokenStream {
-    derive_with(input, copy::derive_copy)
+    try_derive_with(input, copy::derive_copy)
 }
 
-#[proc_macro_derive(Debug)]
+#[proc_macro_derive(Debug, attributes(debug, force_derive))]
 pub fn derive_debug(input: TokenStream) -> TokenStream {
     try_derive_with(input, debug::derive_debug)
 }
 
-#[proc_macro_derive(Default, attributes(default))]
+#[proc_macro_derive(Default, attributes(default, force_derive))]
 pub fn derive_default(input: TokenStream) -> TokenStream {
     try_derive_with(input, default::derive_default)
 }
 
-#[proc_macro_derive(Eq)]
+#[proc_macro_derive(Display, attributes(display, force_derive))]
+pub fn derive_display(input: TokenStream) -> TokenStream {
+    try_derive_with(input, display::derive_display)
+}
+
+#[proc_macro_derive(Eq, attributes(force_derive))]
 pub fn derive_eq(input: TokenStream) -> TokenStream {
-    derive_with(input, eq::derive_eq)
+    try_derive_with(input, eq::derive_eq)
 }
 
-#[proc_macro_derive(Hash)]
+#[proc_macro_derive(From, attributes(force_derive, from))]
+pub fn derive_from(input: TokenStream) -> TokenStream {
+    try_derive_with(input, from::derive_from)
+}
+
+#[proc_macro_derive(Hash, attributes(force_derive, hash))]
 pub fn derive_hash(input: TokenStream) -> TokenStream {
     try_derive_with(input, hash::derive_hash)
 }
 
-#[proc_macro_derive(PartialEq)]
+#[proc_macro_derive(Ord, attributes(force_derive))]
+pub fn derive_ord(input: TokenStream) -> TokenStream {
+    try_derive_with(input, ord::derive_ord)
+}
+
+#[proc_macro_derive(PartialEq, attributes(force_derive, partial_eq))]
 pub fn derive_partial_eq(input: TokenStream) -> TokenStream {
     try_derive_with(input, partial_eq::derive_partial_eq)
 }
+
+#[proc_macro_derive(PartialOrd, attributes(force_derive))]
+pub fn derive_partial_ord(input: TokenStream) -> TokenStream {
+    try_derive_with(input, partial_ord::derive_partial_ord)
+}
+
+#[proc_macro_derive(Send, attributes(force_derive))]
+pub fn derive_send(input: TokenStream) -> TokenStream {
+    try_derive_with(input, send::derive_send)
+}
+
+#[proc_macro_derive(Sync, attributes(force_derive))]
+pub fn derive_sync(input: TokenStream) -> TokenStream {
+    try_derive_with(input, sync::derive_sync)
+}
+
+#[proc_macro_derive(Unpin, attributes(force_derive))]
+pub fn derive_unpin(input: TokenStream) -> TokenStream {
+    try_derive_with(input, unpin::derive_unpin)
+}
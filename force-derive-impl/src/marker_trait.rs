@@ -1,12 +1,52 @@
+use crate::attributes::{self, ContainerAttributes};
 use proc_macro2::TokenStream;
 use syn::DeriveInput;
 
-pub fn derive_marker_trait(input: DeriveInput, marker: TokenStream) -> TokenStream {
+fn derive_with(
+    input: DeriveInput,
+    trait_name: &str,
+    marker: TokenStream,
+    unsafety: TokenStream,
+) -> syn::Result<TokenStream> {
+    // `Eq` only relies on the fields that `PartialEq` compares with the implementation of their own type.
+    let field_types = if trait_name == "Eq" {
+        attributes::bounded_field_types(&input.data, "PartialEq", Some("partial_eq"))?
+    } else {
+        attributes::included_field_types(&input.data, trait_name)?
+    };
+
     let ty = input.ident;
-    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let generics = attributes::apply_bounds(input.generics, &input.attrs, trait_name, marker.clone(), field_types)?;
+
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
 
-    quote::quote! {
+    Ok(quote::quote! {
         #[automatically_derived]
-        impl #impl_generics #marker for #ty #type_generics #where_clause {}
+        #unsafety impl #impl_generics #marker for #ty #type_generics #where_clause {}
+    })
+}
+
+pub fn derive_marker_trait(input: DeriveInput, trait_name: &str, marker: TokenStream) -> syn::Result<TokenStream> {
+    derive_with(input, trait_name, marker, TokenStream::new())
+}
+
+/// Derives an unsafe marker trait, which is only allowed if the user wrote `#[force_derive(unsafe(...))]` to take
+/// responsibility for upholding the safety requirements of the trait.
+pub fn derive_unsafe_marker_trait(
+    input: DeriveInput,
+    trait_name: &str,
+    marker: TokenStream,
+) -> syn::Result<TokenStream> {
+    if !ContainerAttributes::parse(&input.attrs)?.acknowledges_unsafe(trait_name) {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            format!(
+                "Deriving `{0}` is unsafe. Use `#[force_derive(unsafe({0}))]` to acknowledge that the type upholds \
+                 the safety requirements of `{0}`.",
+                trait_name,
+            ),
+        ));
     }
+
+    derive_with(input, trait_name, marker, quote::quote!(unsafe))
 }
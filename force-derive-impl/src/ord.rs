@@ -0,0 +1,281 @@
+use crate::partial_ord::Comparison;
+use crate::{attributes, utilities};
+use proc_macro2::TokenStream;
+use syn::{DeriveInput, Generics, Ident};
+
+fn derive_with(ty: Ident, generics: Generics, body: TokenStream) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let other = utilities::get_local_variable("other");
+
+    quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::Ord for #ty #type_generics
+        #where_clause
+        {
+            fn cmp(&self, #other: &Self) -> ::core::cmp::Ordering {
+                #body
+            }
+        }
+    }
+}
+
+pub fn derive_ord(input: DeriveInput) -> syn::Result<TokenStream> {
+    let comparison = Comparison {
+        trait_name: "Ord",
+        compare: quote::quote!(::core::cmp::Ord::cmp),
+        equal: quote::quote!(::core::cmp::Ordering::Equal),
+    };
+
+    let generics = attributes::apply_bounds(
+        input.generics,
+        &input.attrs,
+        comparison.trait_name,
+        quote::quote!(::core::cmp::Ord),
+        attributes::bounded_field_types(&input.data, comparison.trait_name, None)?,
+    )?;
+
+    let body = comparison.generate_function_body(input.data)?;
+
+    Ok(derive_with(input.ident, generics, body))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utilities;
+
+    #[test]
+    fn test_derive_ord() {
+        let test_cases = [
+            // Empty struct.
+            (
+                quote::quote! {
+                    struct Foo {}
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::cmp::Ord for Foo {
+                        fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                            ::core::cmp::Ordering::Equal
+                        }
+                    }
+                },
+            ),
+            // Struct with a single field.
+            (
+                quote::quote! {
+                    struct Foo<T> {
+                        foo: PhantomData<T>,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::Ord for Foo<T> {
+                        fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                            ::core::cmp::Ord::cmp(&self.foo, &other.foo)
+                        }
+                    }
+                },
+            ),
+            // Struct with two fields and generic constraints.
+            (
+                quote::quote! {
+                    struct Foo<T>
+                    where
+                        u32: Copy,
+                    {
+                        foo: PhantomData<T>,
+                        bar: PhantomData<T>,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::Ord for Foo<T>
+                    where
+                        u32: Copy,
+                    {
+                        fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                            match ::core::cmp::Ord::cmp(&self.foo, &other.foo) {
+                                ::core::cmp::Ordering::Equal =>
+                                    ::core::cmp::Ord::cmp(&self.bar, &other.bar),
+                                ordering => ordering,
+                            }
+                        }
+                    }
+                },
+            ),
+            // Empty tuple.
+            (
+                quote::quote! {
+                    struct Foo();
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::cmp::Ord for Foo {
+                        fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                            ::core::cmp::Ordering::Equal
+                        }
+                    }
+                },
+            ),
+            // Tuple with a single field.
+            (
+                quote::quote! {
+                    struct Foo<T>(PhantomData<T>);
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::Ord for Foo<T> {
+                        fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                            ::core::cmp::Ord::cmp(&self.0, &other.0)
+                        }
+                    }
+                },
+            ),
+            // Tuple with two fields and generic constraints.
+            (
+                quote::quote! {
+                    struct Foo<T>(PhantomData<T>, PhantomData<T>)
+                    where
+                        u32: Copy;
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::Ord for Foo<T>
+                    where
+                        u32: Copy
+                    {
+                        fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                            match ::core::cmp::Ord::cmp(&self.0, &other.0) {
+                                ::core::cmp::Ordering::Equal =>
+                                    ::core::cmp::Ord::cmp(&self.1, &other.1),
+                                ordering => ordering,
+                            }
+                        }
+                    }
+                },
+            ),
+            // Unit.
+            (
+                quote::quote! {
+                    struct Foo;
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::cmp::Ord for Foo {
+                        fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                            ::core::cmp::Ordering::Equal
+                        }
+                    }
+                },
+            ),
+            // Empty enum.
+            (
+                quote::quote! {
+                    enum Foo {}
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::cmp::Ord for Foo {
+                        fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                            match *self {}
+                        }
+                    }
+                },
+            ),
+            // Enum with a single variant.
+            (
+                quote::quote! {
+                    enum Foo<T> {
+                        Tuple1(PhantomData<T>),
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::Ord for Foo<T> {
+                        fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                            match (self, other) {
+                                (Self::Tuple1(self_0,), Self::Tuple1(other_0,),) =>
+                                    ::core::cmp::Ord::cmp(self_0, other_0),
+                            }
+                        }
+                    }
+                },
+            ),
+            // Enum.
+            (
+                quote::quote! {
+                    enum Foo<T>
+                    where
+                        u32: Copy,
+                    {
+                        Struct0 {},
+                        Struct1 { foo: PhantomData<T> },
+                        Struct2 { foo: PhantomData<T>, bar: PhantomData<T> },
+                        Tuple0(),
+                        Tuple1(PhantomData<T>),
+                        Tuple2(PhantomData<T>, PhantomData<T>),
+                        Unit,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::Ord for Foo<T>
+                    where
+                        u32: Copy,
+                    {
+                        fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                            let variant_index = |value: &Self| -> usize {
+                                match value {
+                                    Self::Struct0 { .. } => 0,
+                                    Self::Struct1 { .. } => 1,
+                                    Self::Struct2 { .. } => 2,
+                                    Self::Tuple0 { .. } => 3,
+                                    Self::Tuple1 { .. } => 4,
+                                    Self::Tuple2 { .. } => 5,
+                                    Self::Unit { .. } => 6,
+                                }
+                            };
+
+                            match (self, other) {
+                                (Self::Struct0 {}, Self::Struct0 {},) =>
+                                    ::core::cmp::Ordering::Equal,
+                                (Self::Struct1 { foo: self_foo, }, Self::Struct1 { foo: other_foo, },) =>
+                                    ::core::cmp::Ord::cmp(self_foo, other_foo),
+                                (
+                                    Self::Struct2 { foo: self_foo, bar: self_bar, },
+                                    Self::Struct2 { foo: other_foo, bar: other_bar, },
+                                ) => match ::core::cmp::Ord::cmp(self_foo, other_foo) {
+                                    ::core::cmp::Ordering::Equal =>
+                                        ::core::cmp::Ord::cmp(self_bar, other_bar),
+                                    ordering => ordering,
+                                },
+                                (Self::Tuple0(), Self::Tuple0(),) =>
+                                    ::core::cmp::Ordering::Equal,
+                                (Self::Tuple1(self_0,), Self::Tuple1(other_0,),) =>
+                                    ::core::cmp::Ord::cmp(self_0, other_0),
+                                (Self::Tuple2(self_0, self_1,), Self::Tuple2(other_0, other_1,),) =>
+                                    match ::core::cmp::Ord::cmp(self_0, other_0) {
+                                        ::core::cmp::Ordering::Equal =>
+                                            ::core::cmp::Ord::cmp(self_1, other_1),
+                                        ordering => ordering,
+                                    },
+                                (Self::Unit, Self::Unit,) =>
+                                    ::core::cmp::Ordering::Equal,
+                                _ => ::core::cmp::Ord::cmp(&variant_index(self), &variant_index(other)),
+                            }
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_ord(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+}
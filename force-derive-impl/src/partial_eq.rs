@@ -1,162 +1,174 @@
+use crate::{attributes, utilities};
 use proc_macro2::{Span, TokenStream};
-use syn::{Data, DeriveInput, Fields, Generics, Ident, Index, Variant};
+use syn::{Data, DeriveInput, Field, Fields, Generics, Ident, Variant};
 
 fn unit_type() -> (TokenStream, TokenStream) {
     (quote::quote!(true), quote::quote!(false))
 }
 
-fn generate_variant(variant: &Variant) -> (TokenStream, TokenStream) {
+/// Generates the `eq` and `ne` expressions for `fields`, each paired with references to the field in `self` and
+/// `other`. A field with `#[partial_eq(with = path)]` is compared with `path`, and its `ne` is the negated result. A
+/// field with a key projection is compared by its key.
+fn compare_fields<'a>(
+    fields: impl IntoIterator<Item = (&'a Field, TokenStream, TokenStream)>,
+) -> syn::Result<(TokenStream, TokenStream)> {
+    let mut eq_expressions = Vec::new();
+    let mut ne_expressions = Vec::new();
+
+    for (field, lhs, rhs) in fields {
+        if let Some(with) = attributes::parse_with_argument(field, "partial_eq")? {
+            eq_expressions.push(quote::quote!(#with(#lhs, #rhs)));
+            ne_expressions.push(quote::quote!(!#with(#lhs, #rhs)));
+        } else {
+            let lhs = attributes::project_key(field, lhs)?;
+            let rhs = attributes::project_key(field, rhs)?;
+
+            eq_expressions.push(quote::quote!(::core::cmp::PartialEq::eq(#lhs, #rhs)));
+            ne_expressions.push(quote::quote!(::core::cmp::PartialEq::ne(#lhs, #rhs)));
+        }
+    }
+
+    Ok(if eq_expressions.is_empty() {
+        unit_type()
+    } else {
+        (
+            quote::quote!(#(#eq_expressions)&&*),
+            quote::quote!(#(#ne_expressions)||*),
+        )
+    })
+}
+
+fn generate_variant(variant: &Variant) -> syn::Result<(TokenStream, TokenStream)> {
     let variant_name = &variant.ident;
+    let included_fields = attributes::included_fields(&variant.fields, "PartialEq")?;
 
-    match &variant.fields {
+    Ok(match &variant.fields {
         Fields::Named(fields) => {
-            let eq_pattern_self_fields = fields.named.iter().map(|field| field.ident.as_ref().unwrap());
-
-            let self_variables = eq_pattern_self_fields
-                .clone()
-                .map(|field| quote::format_ident!("self_{}", field))
+            let field_names = included_fields
+                .iter()
+                .map(|(_, field)| field.ident.as_ref().unwrap())
                 .collect::<Vec<_>>();
 
-            let eq_pattern_other_fields = eq_pattern_self_fields.clone();
+            let self_variables = field_names
+                .iter()
+                .map(|field| quote::format_ident!("self_{}", field, span = Span::mixed_site()))
+                .collect::<Vec<_>>();
 
-            let other_variables = eq_pattern_self_fields
-                .clone()
-                .map(|field| quote::format_ident!("other_{}", field))
+            let other_variables = field_names
+                .iter()
+                .map(|field| quote::format_ident!("other_{}", field, span = Span::mixed_site()))
                 .collect::<Vec<_>>();
 
-            let ne_pattern_self_fields = eq_pattern_self_fields.clone();
-            let ne_pattern_other_fields = eq_pattern_other_fields.clone();
+            let rest = utilities::get_rest_pattern(included_fields.len(), fields.named.len());
 
-            let (eq_expression, ne_expression) = if fields.named.is_empty() {
-                (quote::quote!(true), quote::quote!(false))
-            } else {
+            let (eq_expression, ne_expression) = compare_fields(
+                included_fields
+                    .iter()
+                    .zip(self_variables.iter().zip(&other_variables))
+                    .map(|((_, field), (lhs, rhs))| (*field, quote::quote!(#lhs), quote::quote!(#rhs))),
+            )?;
+
+            let patterns = quote::quote! {
                 (
-                    quote::quote!(#(::core::cmp::PartialEq::eq(#self_variables, #other_variables))&&*),
-                    quote::quote!(#(::core::cmp::PartialEq::ne(#self_variables, #other_variables))||*),
+                    Self::#variant_name { #(#field_names: #self_variables,)* #rest },
+                    Self::#variant_name { #(#field_names: #other_variables,)* #rest },
                 )
             };
 
             (
-                quote::quote! {
-                    (
-                        Self::#variant_name { #(#eq_pattern_self_fields: #self_variables,)* },
-                        Self::#variant_name { #(#eq_pattern_other_fields: #other_variables,)* },
-                    ) => #eq_expression
-                },
-                quote::quote! {
-                    (
-                        Self::#variant_name { #(#ne_pattern_self_fields: #self_variables,)* },
-                        Self::#variant_name { #(#ne_pattern_other_fields: #other_variables,)* },
-                    ) => #ne_expression
-                },
+                quote::quote!(#patterns => #eq_expression),
+                quote::quote!(#patterns => #ne_expression),
             )
         }
         Fields::Unnamed(fields) => {
-            let self_variables = (0..fields.unnamed.len())
-                .map(|field| quote::format_ident!("self_{}", field))
+            let self_variables = included_fields
+                .iter()
+                .map(|(i, _)| (*i, quote::format_ident!("self_{}", i, span = Span::mixed_site())))
                 .collect::<Vec<_>>();
 
-            let other_variables = (0..fields.unnamed.len())
-                .map(|field| quote::format_ident!("other_{}", field))
+            let other_variables = included_fields
+                .iter()
+                .map(|(i, _)| (*i, quote::format_ident!("other_{}", i, span = Span::mixed_site())))
                 .collect::<Vec<_>>();
 
-            let (eq_expression, ne_expression) = if fields.unnamed.is_empty() {
-                (quote::quote!(true), quote::quote!(false))
-            } else {
+            let self_patterns = utilities::get_tuple_patterns(fields.unnamed.len(), &self_variables);
+            let other_patterns = utilities::get_tuple_patterns(fields.unnamed.len(), &other_variables);
+
+            let (eq_expression, ne_expression) = compare_fields(
+                included_fields
+                    .iter()
+                    .zip(self_variables.iter().zip(&other_variables))
+                    .map(|((_, field), ((_, lhs), (_, rhs)))| (*field, quote::quote!(#lhs), quote::quote!(#rhs))),
+            )?;
+
+            let patterns = quote::quote! {
                 (
-                    quote::quote!(#(::core::cmp::PartialEq::eq(#self_variables, #other_variables))&&*),
-                    quote::quote!(#(::core::cmp::PartialEq::ne(#self_variables, #other_variables))||*),
+                    Self::#variant_name(#(#self_patterns,)*),
+                    Self::#variant_name(#(#other_patterns,)*),
                 )
             };
 
             (
-                quote::quote! {
-                    (
-                        Self::#variant_name(#(#self_variables,)*),
-                        Self::#variant_name(#(#other_variables,)*),
-                    ) => #eq_expression
-                },
-                quote::quote! {
-                    (
-                        Self::#variant_name(#(#self_variables,)*),
-                        Self::#variant_name(#(#other_variables,)*),
-                    ) => #ne_expression
-                },
+                quote::quote!(#patterns => #eq_expression),
+                quote::quote!(#patterns => #ne_expression),
             )
         }
         Fields::Unit => (
             quote::quote! { (Self::#variant_name, Self::#variant_name,) => true },
             quote::quote! { (Self::#variant_name, Self::#variant_name,) => false },
         ),
-    }
+    })
 }
 
-fn generate_function_bodies(span: Span, data: Data) -> syn::Result<(TokenStream, TokenStream)> {
-    Ok(match data {
-        Data::Struct(data_struct) => match data_struct.fields {
-            Fields::Named(fields) => {
-                if fields.named.is_empty() {
-                    unit_type()
-                } else {
-                    let eq_self_fields = fields.named.iter().map(|field| field.ident.as_ref().unwrap());
-                    let eq_other_fields = eq_self_fields.clone();
-                    let ne_self_fields = eq_self_fields.clone();
-                    let ne_other_fields = eq_self_fields.clone();
-
-                    (
-                        quote::quote!(#(::core::cmp::PartialEq::eq(&self.#eq_self_fields, &other.#eq_other_fields))&&*),
-                        quote::quote!(#(::core::cmp::PartialEq::ne(&self.#ne_self_fields, &other.#ne_other_fields))||*),
-                    )
-                }
-            }
-            Fields::Unnamed(fields) => {
-                if fields.unnamed.is_empty() {
-                    unit_type()
-                } else {
-                    let eq_self_fields = (0..fields.unnamed.len()).map(Index::from);
-                    let eq_other_fields = eq_self_fields.clone();
-                    let ne_self_fields = eq_self_fields.clone();
-                    let ne_other_fields = eq_self_fields.clone();
+fn generate_function_bodies(data: Data) -> syn::Result<(TokenStream, TokenStream)> {
+    let other = utilities::get_local_variable("other");
 
-                    (
-                        quote::quote!(#(::core::cmp::PartialEq::eq(&self.#eq_self_fields, &other.#eq_other_fields))&&*),
-                        quote::quote!(#(::core::cmp::PartialEq::ne(&self.#ne_self_fields, &other.#ne_other_fields))||*),
-                    )
-                }
-            }
-            Fields::Unit => unit_type(),
-        },
+    Ok(match data {
+        Data::Struct(data_struct) => compare_fields(
+            attributes::included_fields(&data_struct.fields, "PartialEq")?
+                .into_iter()
+                .map(|(i, field)| {
+                    let member = utilities::get_member(i, field);
+
+                    (field, quote::quote!(&self.#member), quote::quote!(&#other.#member))
+                }),
+        )?,
         Data::Enum(data_enum) => {
             let variants = data_enum.variants;
 
             if let Some(first) = variants.first() {
                 if variants.len() == 1 {
-                    let (eq, ne) = generate_variant(first);
+                    let (eq, ne) = generate_variant(first)?;
 
                     (
                         quote::quote! {
-                            match (self, other) {
+                            match (self, #other) {
                                 #eq,
                             }
                         },
                         quote::quote! {
-                            match (self, other) {
+                            match (self, #other) {
                                 #ne,
                             }
                         },
                     )
                 } else {
-                    let (eq, ne): (Vec<_>, Vec<_>) = variants.iter().map(generate_variant).unzip();
+                    let (eq, ne): (Vec<_>, Vec<_>) = variants
+                        .iter()
+                        .map(generate_variant)
+                        .collect::<syn::Result<Vec<_>>>()?
+                        .into_iter()
+                        .unzip();
 
                     (
                         quote::quote! {
-                            match (self, other) {
+                            match (self, #other) {
                                 #(#eq,)*
                                 _ => false,
                             }
                         },
                         quote::quote! {
-                            match (self, other) {
+                            match (self, #other) {
                                 #(#ne,)*
                                 _ => true,
                             }
@@ -167,23 +179,29 @@ fn generate_function_bodies(span: Span, data: Data) -> syn::Result<(TokenStream,
                 (quote::quote! { match *self {} }, quote::quote! { match *self {} })
             }
         }
-        Data::Union(_) => return Err(syn::Error::new(span, "Cannot derive `PartialEq` on a `union`.")),
+        Data::Union(data_union) => {
+            return Err(syn::Error::new(
+                data_union.union_token.span,
+                "Cannot derive `PartialEq` on a `union`.",
+            ))
+        }
     })
 }
 
 fn derive_with(ty: Ident, generics: Generics, eq_body: TokenStream, ne_body: TokenStream) -> TokenStream {
     let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let other = utilities::get_local_variable("other");
 
     quote::quote! {
         #[automatically_derived]
         impl #impl_generics ::core::cmp::PartialEq for #ty #type_generics
         #where_clause
         {
-            fn eq(&self, other: &Self) -> bool {
+            fn eq(&self, #other: &Self) -> bool {
                 #eq_body
             }
 
-            fn ne(&self, other: &Self) -> bool {
+            fn ne(&self, #other: &Self) -> bool {
                 #ne_body
             }
         }
@@ -191,8 +209,17 @@ fn derive_with(ty: Ident, generics: Generics, eq_body: TokenStream, ne_body: Tok
 }
 
 pub fn derive_partial_eq(input: DeriveInput) -> syn::Result<TokenStream> {
-    generate_function_bodies(input.ident.span(), input.data)
-        .map(|(eq_body, ne_body)| derive_with(input.ident, input.generics, eq_body, ne_body))
+    let generics = attributes::apply_bounds(
+        input.generics,
+        &input.attrs,
+        "PartialEq",
+        quote::quote!(::core::cmp::PartialEq),
+        attributes::bounded_field_types(&input.data, "PartialEq", Some("partial_eq"))?,
+    )?;
+
+    let (eq_body, ne_body) = generate_function_bodies(input.data)?;
+
+    Ok(derive_with(input.ident, generics, eq_body, ne_body))
 }
 
 #[cfg(test)]
@@ -469,4 +496,340 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_derive_partial_eq_skip() {
+        let test_cases = [
+            // Struct.
+            (
+                quote::quote! {
+                    struct Foo<T> {
+                        foo: u32,
+                        #[force_derive(skip)]
+                        bar: Cell<T>,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::PartialEq for Foo<T> {
+                        fn eq(&self, other: &Self) -> bool {
+                            ::core::cmp::PartialEq::eq(&self.foo, &other.foo)
+                        }
+
+                        fn ne(&self, other: &Self) -> bool {
+                            ::core::cmp::PartialEq::ne(&self.foo, &other.foo)
+                        }
+                    }
+                },
+            ),
+            // Tuple with every field skipped.
+            (
+                quote::quote! {
+                    struct Foo<T>(#[force_derive(skip(PartialEq))] Cell<T>);
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::PartialEq for Foo<T> {
+                        fn eq(&self, other: &Self) -> bool {
+                            true
+                        }
+
+                        fn ne(&self, other: &Self) -> bool {
+                            false
+                        }
+                    }
+                },
+            ),
+            // Enum.
+            (
+                quote::quote! {
+                    enum Foo<T> {
+                        Struct { #[force_derive(skip)] foo: Cell<T>, bar: u32 },
+                        Tuple(#[force_derive(skip(PartialEq, Hash))] Cell<T>, u32),
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::PartialEq for Foo<T> {
+                        fn eq(&self, other: &Self) -> bool {
+                            match (self, other) {
+                                (Self::Struct { bar: self_bar, .. }, Self::Struct { bar: other_bar, .. },) =>
+                                    ::core::cmp::PartialEq::eq(self_bar, other_bar),
+                                (Self::Tuple(_, self_1,), Self::Tuple(_, other_1,),) =>
+                                    ::core::cmp::PartialEq::eq(self_1, other_1),
+                                _ => false,
+                            }
+                        }
+
+                        fn ne(&self, other: &Self) -> bool {
+                            match (self, other) {
+                                (Self::Struct { bar: self_bar, .. }, Self::Struct { bar: other_bar, .. },) =>
+                                    ::core::cmp::PartialEq::ne(self_bar, other_bar),
+                                (Self::Tuple(_, self_1,), Self::Tuple(_, other_1,),) =>
+                                    ::core::cmp::PartialEq::ne(self_1, other_1),
+                                _ => true,
+                            }
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_partial_eq(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_partial_eq_with() {
+        let test_cases = [
+            // Struct.
+            (
+                quote::quote! {
+                    struct Foo<T> {
+                        foo: u32,
+                        #[partial_eq(with = approx_eq)]
+                        bar: T,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::PartialEq for Foo<T> {
+                        fn eq(&self, other: &Self) -> bool {
+                            ::core::cmp::PartialEq::eq(&self.foo, &other.foo) && approx_eq(&self.bar, &other.bar)
+                        }
+
+                        fn ne(&self, other: &Self) -> bool {
+                            ::core::cmp::PartialEq::ne(&self.foo, &other.foo) || !approx_eq(&self.bar, &other.bar)
+                        }
+                    }
+                },
+            ),
+            // Enum.
+            (
+                quote::quote! {
+                    enum Foo<T> {
+                        Struct { #[partial_eq(with = ptr::eq)] foo: Box<T> },
+                        Tuple(#[partial_eq(with = approx_eq)] T, #[force_derive(skip)] T),
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::PartialEq for Foo<T> {
+                        fn eq(&self, other: &Self) -> bool {
+                            match (self, other) {
+                                (Self::Struct { foo: self_foo, }, Self::Struct { foo: other_foo, },) =>
+                                    ptr::eq(self_foo, other_foo),
+                                (Self::Tuple(self_0, _,), Self::Tuple(other_0, _,),) => approx_eq(self_0, other_0),
+                                _ => false,
+                            }
+                        }
+
+                        fn ne(&self, other: &Self) -> bool {
+                            match (self, other) {
+                                (Self::Struct { foo: self_foo, }, Self::Struct { foo: other_foo, },) =>
+                                    !ptr::eq(self_foo, other_foo),
+                                (Self::Tuple(self_0, _,), Self::Tuple(other_0, _,),) => !approx_eq(self_0, other_0),
+                                _ => true,
+                            }
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_partial_eq(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_partial_eq_wrong_skip_attribute() {
+        let test_cases = [
+            quote::quote! {
+                struct Foo {
+                    #[force_derive(skip(Clone))]
+                    foo: u32,
+                }
+            },
+            quote::quote! {
+                struct Foo {
+                    #[force_derive(unknown)]
+                    foo: u32,
+                }
+            },
+            quote::quote! {
+                struct Foo(#[force_derive = "skip"] u32);
+            },
+            quote::quote! {
+                struct Foo(#[partial_eq(unknown = approx_eq)] f32);
+            },
+            quote::quote! {
+                struct Foo(#[partial_eq(with = "approx_eq")] f32);
+            },
+        ];
+
+        for input in test_cases {
+            assert!(super::derive_partial_eq(utilities::parse_derive_input(input).unwrap()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_derive_partial_eq_key() {
+        let test_cases = [
+            // Struct.
+            (
+                quote::quote! {
+                    #[force_derive(perfect)]
+                    struct Foo<T> {
+                        #[force_derive(key = "|name| name.to_lowercase()")]
+                        foo: String,
+                        bar: T,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::PartialEq for Foo<T>
+                    where
+                        T: ::core::cmp::PartialEq
+                    {
+                        fn eq(&self, other: &Self) -> bool {
+                            ::core::cmp::PartialEq::eq(
+                                &{
+                                    fn __force_derive_project_key<'a, T: ?::core::marker::Sized, K>(
+                                        value: &'a T,
+                                        key: impl ::core::ops::FnOnce(&'a T) -> K,
+                                    ) -> K {
+                                        key(value)
+                                    }
+
+                                    __force_derive_project_key::<String, _>(&self.foo, |name| name.to_lowercase())
+                                },
+                                &{
+                                    fn __force_derive_project_key<'a, T: ?::core::marker::Sized, K>(
+                                        value: &'a T,
+                                        key: impl ::core::ops::FnOnce(&'a T) -> K,
+                                    ) -> K {
+                                        key(value)
+                                    }
+
+                                    __force_derive_project_key::<String, _>(&other.foo, |name| name.to_lowercase())
+                                }
+                            ) && ::core::cmp::PartialEq::eq(&self.bar, &other.bar)
+                        }
+
+                        fn ne(&self, other: &Self) -> bool {
+                            ::core::cmp::PartialEq::ne(
+                                &{
+                                    fn __force_derive_project_key<'a, T: ?::core::marker::Sized, K>(
+                                        value: &'a T,
+                                        key: impl ::core::ops::FnOnce(&'a T) -> K,
+                                    ) -> K {
+                                        key(value)
+                                    }
+
+                                    __force_derive_project_key::<String, _>(&self.foo, |name| name.to_lowercase())
+                                },
+                                &{
+                                    fn __force_derive_project_key<'a, T: ?::core::marker::Sized, K>(
+                                        value: &'a T,
+                                        key: impl ::core::ops::FnOnce(&'a T) -> K,
+                                    ) -> K {
+                                        key(value)
+                                    }
+
+                                    __force_derive_project_key::<String, _>(&other.foo, |name| name.to_lowercase())
+                                }
+                            ) || ::core::cmp::PartialEq::ne(&self.bar, &other.bar)
+                        }
+                    }
+                },
+            ),
+            // Enum.
+            (
+                quote::quote! {
+                    enum Foo<T> {
+                        Tuple(#[force_derive(key = "Tagged::id")] Tagged<T>),
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::PartialEq for Foo<T> {
+                        fn eq(&self, other: &Self) -> bool {
+                            match (self, other) {
+                                (Self::Tuple(self_0,), Self::Tuple(other_0,),) => ::core::cmp::PartialEq::eq(
+                                    &{
+                                        fn __force_derive_project_key<'a, T: ?::core::marker::Sized, K>(
+                                            value: &'a T,
+                                            key: impl ::core::ops::FnOnce(&'a T) -> K,
+                                        ) -> K {
+                                            key(value)
+                                        }
+
+                                        __force_derive_project_key::<Tagged<T>, _>(self_0, Tagged::id)
+                                    },
+                                    &{
+                                        fn __force_derive_project_key<'a, T: ?::core::marker::Sized, K>(
+                                            value: &'a T,
+                                            key: impl ::core::ops::FnOnce(&'a T) -> K,
+                                        ) -> K {
+                                            key(value)
+                                        }
+
+                                        __force_derive_project_key::<Tagged<T>, _>(other_0, Tagged::id)
+                                    }
+                                ),
+                            }
+                        }
+
+                        fn ne(&self, other: &Self) -> bool {
+                            match (self, other) {
+                                (Self::Tuple(self_0,), Self::Tuple(other_0,),) => ::core::cmp::PartialEq::ne(
+                                    &{
+                                        fn __force_derive_project_key<'a, T: ?::core::marker::Sized, K>(
+                                            value: &'a T,
+                                            key: impl ::core::ops::FnOnce(&'a T) -> K,
+                                        ) -> K {
+                                            key(value)
+                                        }
+
+                                        __force_derive_project_key::<Tagged<T>, _>(self_0, Tagged::id)
+                                    },
+                                    &{
+                                        fn __force_derive_project_key<'a, T: ?::core::marker::Sized, K>(
+                                            value: &'a T,
+                                            key: impl ::core::ops::FnOnce(&'a T) -> K,
+                                        ) -> K {
+                                            key(value)
+                                        }
+
+                                        __force_derive_project_key::<Tagged<T>, _>(other_0, Tagged::id)
+                                    }
+                                ),
+                            }
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_partial_eq(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
 }
@@ -0,0 +1,553 @@
+use crate::{attributes, utilities};
+use proc_macro2::{Literal, Span, TokenStream};
+use syn::{Data, DeriveInput, Field, Fields, Generics, Ident, Variant};
+
+pub struct Comparison {
+    pub trait_name: &'static str,
+    pub compare: TokenStream,
+    pub equal: TokenStream,
+}
+
+impl Comparison {
+    fn chain(&self, comparisons: Vec<TokenStream>) -> TokenStream {
+        let equal = &self.equal;
+        let ordering = utilities::get_local_variable("ordering");
+        let mut comparisons = comparisons.into_iter().rev();
+
+        if let Some(last) = comparisons.next() {
+            comparisons.fold(last, |rest, comparison| {
+                quote::quote! {
+                    match #comparison {
+                        #equal => #rest,
+                        #ordering => #ordering,
+                    }
+                }
+            })
+        } else {
+            equal.clone()
+        }
+    }
+
+    /// Chains the comparisons of `fields`, each paired with references to the field in `self` and `other`. A field
+    /// with a key projection is compared by its key.
+    fn compare_fields<'a>(
+        &self,
+        fields: impl IntoIterator<Item = (&'a Field, TokenStream, TokenStream)>,
+    ) -> syn::Result<TokenStream> {
+        let compare = &self.compare;
+
+        Ok(self.chain(
+            fields
+                .into_iter()
+                .map(|(field, lhs, rhs)| {
+                    let lhs = attributes::project_key(field, lhs)?;
+                    let rhs = attributes::project_key(field, rhs)?;
+
+                    Ok(quote::quote!(#compare(#lhs, #rhs)))
+                })
+                .collect::<syn::Result<_>>()?,
+        ))
+    }
+
+    fn compare_variant(&self, variant: &Variant) -> syn::Result<TokenStream> {
+        let variant_name = &variant.ident;
+        let included_fields = attributes::included_fields(&variant.fields, self.trait_name)?;
+
+        Ok(match &variant.fields {
+            Fields::Named(fields) => {
+                let self_pattern_fields = included_fields.iter().map(|(_, field)| field.ident.as_ref().unwrap());
+
+                let other_pattern_fields = self_pattern_fields.clone();
+
+                let self_variables = self_pattern_fields
+                    .clone()
+                    .map(|field| quote::format_ident!("self_{}", field, span = Span::mixed_site()))
+                    .collect::<Vec<_>>();
+
+                let other_variables = self_pattern_fields
+                    .clone()
+                    .map(|field| quote::format_ident!("other_{}", field, span = Span::mixed_site()))
+                    .collect::<Vec<_>>();
+
+                let rest = utilities::get_rest_pattern(included_fields.len(), fields.named.len());
+
+                let expression = self.compare_fields(
+                    included_fields
+                        .iter()
+                        .zip(self_variables.iter().zip(&other_variables))
+                        .map(|((_, field), (lhs, rhs))| (*field, quote::quote!(#lhs), quote::quote!(#rhs))),
+                )?;
+
+                quote::quote! {
+                    (
+                        Self::#variant_name { #(#self_pattern_fields: #self_variables,)* #rest },
+                        Self::#variant_name { #(#other_pattern_fields: #other_variables,)* #rest },
+                    ) => #expression
+                }
+            }
+            Fields::Unnamed(fields) => {
+                let self_variables = included_fields
+                    .iter()
+                    .map(|(i, _)| (*i, quote::format_ident!("self_{}", i, span = Span::mixed_site())))
+                    .collect::<Vec<_>>();
+
+                let other_variables = included_fields
+                    .iter()
+                    .map(|(i, _)| (*i, quote::format_ident!("other_{}", i, span = Span::mixed_site())))
+                    .collect::<Vec<_>>();
+
+                let self_patterns = utilities::get_tuple_patterns(fields.unnamed.len(), &self_variables);
+                let other_patterns = utilities::get_tuple_patterns(fields.unnamed.len(), &other_variables);
+
+                let expression = self.compare_fields(
+                    included_fields
+                        .iter()
+                        .zip(self_variables.iter().zip(&other_variables))
+                        .map(|((_, field), ((_, lhs), (_, rhs)))| (*field, quote::quote!(#lhs), quote::quote!(#rhs))),
+                )?;
+
+                quote::quote! {
+                    (
+                        Self::#variant_name(#(#self_patterns,)*),
+                        Self::#variant_name(#(#other_patterns,)*),
+                    ) => #expression
+                }
+            }
+            Fields::Unit => {
+                let equal = &self.equal;
+
+                quote::quote! { (Self::#variant_name, Self::#variant_name,) => #equal }
+            }
+        })
+    }
+
+    pub fn generate_function_body(&self, data: Data) -> syn::Result<TokenStream> {
+        let compare = &self.compare;
+        let other = utilities::get_local_variable("other");
+
+        Ok(match data {
+            Data::Struct(data_struct) => self.compare_fields(
+                attributes::included_fields(&data_struct.fields, self.trait_name)?
+                    .into_iter()
+                    .map(|(i, field)| {
+                        let member = utilities::get_member(i, field);
+
+                        (field, quote::quote!(&self.#member), quote::quote!(&#other.#member))
+                    }),
+            )?,
+            Data::Enum(data_enum) => {
+                let variants = data_enum.variants;
+
+                if let Some(first) = variants.first() {
+                    if variants.len() == 1 {
+                        let arm = self.compare_variant(first)?;
+
+                        quote::quote! {
+                            match (self, #other) {
+                                #arm,
+                            }
+                        }
+                    } else {
+                        let arms = variants
+                            .iter()
+                            .map(|variant| self.compare_variant(variant))
+                            .collect::<syn::Result<Vec<_>>>()?;
+
+                        let variant_index = utilities::get_local_variable("variant_index");
+                        let value = utilities::get_local_variable("value");
+                        let variant_names = variants.iter().map(|variant| &variant.ident);
+                        let variant_indices = (0..variants.len()).map(Literal::usize_unsuffixed);
+
+                        quote::quote! {
+                            let #variant_index = |#value: &Self| -> usize {
+                                match #value {
+                                    #(Self::#variant_names { .. } => #variant_indices,)*
+                                }
+                            };
+
+                            match (self, #other) {
+                                #(#arms,)*
+                                _ => #compare(&#variant_index(self), &#variant_index(#other)),
+                            }
+                        }
+                    }
+                } else {
+                    quote::quote! { match *self {} }
+                }
+            }
+            Data::Union(data_union) => {
+                return Err(syn::Error::new(
+                    data_union.union_token.span,
+                    format!("Cannot derive `{}` on a `union`.", self.trait_name),
+                ))
+            }
+        })
+    }
+}
+
+fn derive_with(ty: Ident, generics: Generics, body: TokenStream) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let other = utilities::get_local_variable("other");
+
+    quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::PartialOrd for #ty #type_generics
+        #where_clause
+        {
+            fn partial_cmp(&self, #other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                #body
+            }
+        }
+    }
+}
+
+pub fn derive_partial_ord(input: DeriveInput) -> syn::Result<TokenStream> {
+    let comparison = Comparison {
+        trait_name: "PartialOrd",
+        compare: quote::quote!(::core::cmp::PartialOrd::partial_cmp),
+        equal: quote::quote!(::core::option::Option::Some(::core::cmp::Ordering::Equal)),
+    };
+
+    let generics = attributes::apply_bounds(
+        input.generics,
+        &input.attrs,
+        comparison.trait_name,
+        quote::quote!(::core::cmp::PartialOrd),
+        attributes::bounded_field_types(&input.data, comparison.trait_name, None)?,
+    )?;
+
+    let body = comparison.generate_function_body(input.data)?;
+
+    Ok(derive_with(input.ident, generics, body))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utilities;
+
+    #[test]
+    fn test_derive_partial_ord() {
+        let test_cases = [
+            // Empty struct.
+            (
+                quote::quote! {
+                    struct Foo {}
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::cmp::PartialOrd for Foo {
+                        fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                            ::core::option::Option::Some(::core::cmp::Ordering::Equal)
+                        }
+                    }
+                },
+            ),
+            // Struct with a single field.
+            (
+                quote::quote! {
+                    struct Foo<T> {
+                        foo: PhantomData<T>,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::PartialOrd for Foo<T> {
+                        fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                            ::core::cmp::PartialOrd::partial_cmp(&self.foo, &other.foo)
+                        }
+                    }
+                },
+            ),
+            // Struct with two fields and generic constraints.
+            (
+                quote::quote! {
+                    struct Foo<T>
+                    where
+                        u32: Copy,
+                    {
+                        foo: PhantomData<T>,
+                        bar: PhantomData<T>,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::PartialOrd for Foo<T>
+                    where
+                        u32: Copy,
+                    {
+                        fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                            match ::core::cmp::PartialOrd::partial_cmp(&self.foo, &other.foo) {
+                                ::core::option::Option::Some(::core::cmp::Ordering::Equal) =>
+                                    ::core::cmp::PartialOrd::partial_cmp(&self.bar, &other.bar),
+                                ordering => ordering,
+                            }
+                        }
+                    }
+                },
+            ),
+            // Empty tuple.
+            (
+                quote::quote! {
+                    struct Foo();
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::cmp::PartialOrd for Foo {
+                        fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                            ::core::option::Option::Some(::core::cmp::Ordering::Equal)
+                        }
+                    }
+                },
+            ),
+            // Tuple with a single field.
+            (
+                quote::quote! {
+                    struct Foo<T>(PhantomData<T>);
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::PartialOrd for Foo<T> {
+                        fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                            ::core::cmp::PartialOrd::partial_cmp(&self.0, &other.0)
+                        }
+                    }
+                },
+            ),
+            // Tuple with two fields and generic constraints.
+            (
+                quote::quote! {
+                    struct Foo<T>(PhantomData<T>, PhantomData<T>)
+                    where
+                        u32: Copy;
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::PartialOrd for Foo<T>
+                    where
+                        u32: Copy
+                    {
+                        fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                            match ::core::cmp::PartialOrd::partial_cmp(&self.0, &other.0) {
+                                ::core::option::Option::Some(::core::cmp::Ordering::Equal) =>
+                                    ::core::cmp::PartialOrd::partial_cmp(&self.1, &other.1),
+                                ordering => ordering,
+                            }
+                        }
+                    }
+                },
+            ),
+            // Unit.
+            (
+                quote::quote! {
+                    struct Foo;
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::cmp::PartialOrd for Foo {
+                        fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                            ::core::option::Option::Some(::core::cmp::Ordering::Equal)
+                        }
+                    }
+                },
+            ),
+            // Empty enum.
+            (
+                quote::quote! {
+                    enum Foo {}
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::cmp::PartialOrd for Foo {
+                        fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                            match *self {}
+                        }
+                    }
+                },
+            ),
+            // Enum with a single variant.
+            (
+                quote::quote! {
+                    enum Foo<T> {
+                        Tuple1(PhantomData<T>),
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::PartialOrd for Foo<T> {
+                        fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                            match (self, other) {
+                                (Self::Tuple1(self_0,), Self::Tuple1(other_0,),) =>
+                                    ::core::cmp::PartialOrd::partial_cmp(self_0, other_0),
+                            }
+                        }
+                    }
+                },
+            ),
+            // Enum.
+            (
+                quote::quote! {
+                    enum Foo<T>
+                    where
+                        u32: Copy,
+                    {
+                        Struct0 {},
+                        Struct1 { foo: PhantomData<T> },
+                        Struct2 { foo: PhantomData<T>, bar: PhantomData<T> },
+                        Tuple0(),
+                        Tuple1(PhantomData<T>),
+                        Tuple2(PhantomData<T>, PhantomData<T>),
+                        Unit,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::cmp::PartialOrd for Foo<T>
+                    where
+                        u32: Copy,
+                    {
+                        fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                            let variant_index = |value: &Self| -> usize {
+                                match value {
+                                    Self::Struct0 { .. } => 0,
+                                    Self::Struct1 { .. } => 1,
+                                    Self::Struct2 { .. } => 2,
+                                    Self::Tuple0 { .. } => 3,
+                                    Self::Tuple1 { .. } => 4,
+                                    Self::Tuple2 { .. } => 5,
+                                    Self::Unit { .. } => 6,
+                                }
+                            };
+
+                            match (self, other) {
+                                (Self::Struct0 {}, Self::Struct0 {},) =>
+                                    ::core::option::Option::Some(::core::cmp::Ordering::Equal),
+                                (Self::Struct1 { foo: self_foo, }, Self::Struct1 { foo: other_foo, },) =>
+                                    ::core::cmp::PartialOrd::partial_cmp(self_foo, other_foo),
+                                (
+                                    Self::Struct2 { foo: self_foo, bar: self_bar, },
+                                    Self::Struct2 { foo: other_foo, bar: other_bar, },
+                                ) => match ::core::cmp::PartialOrd::partial_cmp(self_foo, other_foo) {
+                                    ::core::option::Option::Some(::core::cmp::Ordering::Equal) =>
+                                        ::core::cmp::PartialOrd::partial_cmp(self_bar, other_bar),
+                                    ordering => ordering,
+                                },
+                                (Self::Tuple0(), Self::Tuple0(),) =>
+                                    ::core::option::Option::Some(::core::cmp::Ordering::Equal),
+                                (Self::Tuple1(self_0,), Self::Tuple1(other_0,),) =>
+                                    ::core::cmp::PartialOrd::partial_cmp(self_0, other_0),
+                                (Self::Tuple2(self_0, self_1,), Self::Tuple2(other_0, other_1,),) =>
+                                    match ::core::cmp::PartialOrd::partial_cmp(self_0, other_0) {
+                                        ::core::option::Option::Some(::core::cmp::Ordering::Equal) =>
+                                            ::core::cmp::PartialOrd::partial_cmp(self_1, other_1),
+                                        ordering => ordering,
+                                    },
+                                (Self::Unit, Self::Unit,) =>
+                                    ::core::option::Option::Some(::core::cmp::Ordering::Equal),
+                                _ => ::core::cmp::PartialOrd::partial_cmp(&variant_index(self), &variant_index(other)),
+                            }
+                        }
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_partial_ord(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_partial_ord_key() {
+        let test_cases = [(
+            quote::quote! {
+                struct Foo {
+                    #[force_derive(key = "|value| value.abs()")]
+                    foo: i32,
+                    bar: u32,
+                }
+            },
+            quote::quote! {
+                #[automatically_derived]
+                impl ::core::cmp::PartialOrd for Foo {
+                    fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                        match ::core::cmp::PartialOrd::partial_cmp(
+                            &{
+                                fn __force_derive_project_key<'a, T: ?::core::marker::Sized, K>(
+                                    value: &'a T,
+                                    key: impl ::core::ops::FnOnce(&'a T) -> K,
+                                ) -> K {
+                                    key(value)
+                                }
+
+                                __force_derive_project_key::<i32, _>(&self.foo, |value| value.abs())
+                            },
+                            &{
+                                fn __force_derive_project_key<'a, T: ?::core::marker::Sized, K>(
+                                    value: &'a T,
+                                    key: impl ::core::ops::FnOnce(&'a T) -> K,
+                                ) -> K {
+                                    key(value)
+                                }
+
+                                __force_derive_project_key::<i32, _>(&other.foo, |value| value.abs())
+                            }
+                        ) {
+                            ::core::option::Option::Some(::core::cmp::Ordering::Equal) =>
+                                ::core::cmp::PartialOrd::partial_cmp(&self.bar, &other.bar),
+                            ordering => ordering,
+                        }
+                    }
+                }
+            },
+        )];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_partial_ord(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_partial_ord_partial_eq_with() {
+        let test_cases = [
+            // Skipped in both `PartialEq` and `PartialOrd`.
+            (
+                quote::quote! {
+                    struct Foo(#[partial_eq(with = approx_eq)] #[force_derive(skip(PartialEq, PartialOrd))] f64);
+                },
+                true,
+            ),
+            // Ordered with the `PartialOrd` implementation of the field type.
+            (
+                quote::quote! {
+                    struct Foo(#[partial_eq(with = approx_eq)] f64);
+                },
+                false,
+            ),
+            (
+                quote::quote! {
+                    enum Foo {
+                        Bar(#[partial_eq(with = approx_eq)] f64),
+                    }
+                },
+                false,
+            ),
+        ];
+
+        for (input, ok) in test_cases {
+            assert_eq!(
+                super::derive_partial_ord(utilities::parse_derive_input(input).unwrap()).is_ok(),
+                ok
+            );
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use proc_macro2::TokenStream;
+use syn::DeriveInput;
+
+pub fn derive_send(input: DeriveInput) -> syn::Result<TokenStream> {
+    crate::marker_trait::derive_unsafe_marker_trait(input, "Send", quote::quote!(::core::marker::Send))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utilities;
+
+    #[test]
+    fn test_derive_send() {
+        let test_cases = [
+            // Struct.
+            (
+                quote::quote! {
+                    #[force_derive(unsafe(Send))]
+                    struct Foo<T> {
+                        foo: *const T,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    unsafe impl<T> ::core::marker::Send for Foo<T> {}
+                },
+            ),
+            // Enum with generic constraints.
+            (
+                quote::quote! {
+                    #[force_derive(unsafe(Send))]
+                    enum Foo<T>
+                    where
+                        u32: Copy,
+                    {
+                        Tuple1(*const T),
+                        Unit,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    unsafe impl<T> ::core::marker::Send for Foo<T>
+                    where
+                        u32: Copy,
+                    {
+                    }
+                },
+            ),
+            // Custom bounds.
+            (
+                quote::quote! {
+                    #[force_derive(unsafe(Send), bound(Send = "T: Send"))]
+                    struct Foo<T>(*const T);
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    unsafe impl<T> ::core::marker::Send for Foo<T>
+                    where
+                        T: Send
+                    {
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_send(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_send_without_acknowledgement() {
+        let test_cases = [
+            quote::quote! {
+                struct Foo<T>(*const T);
+            },
+            quote::quote! {
+                #[force_derive(unsafe(Sync))]
+                struct Foo<T>(*const T);
+            },
+        ];
+
+        for input in test_cases {
+            assert!(super::derive_send(utilities::parse_derive_input(input).unwrap()).is_err());
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::attributes::ContainerAttributes;
+use std::collections::HashSet;
+use syn::{Data, DeriveInput, Generics, Ident, TraitBoundModifier, Type, TypeParamBound, WherePredicate};
+
+fn is_maybe_sized_bound(bound: &TypeParamBound) -> bool {
+    matches!(
+        bound,
+        TypeParamBound::Trait(trait_bound)
+            if matches!(trait_bound.modifier, TraitBoundModifier::Maybe(_)) && trait_bound.path.is_ident("Sized")
+    )
+}
+
+fn get_type_parameter(ty: &Type) -> Option<&Ident> {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path.path.get_ident(),
+        Type::Group(type_group) => get_type_parameter(&type_group.elem),
+        Type::Paren(type_paren) => get_type_parameter(&type_paren.elem),
+        _ => None,
+    }
+}
+
+fn get_maybe_sized_type_parameters(generics: &Generics) -> HashSet<&Ident> {
+    let mut result = generics
+        .type_params()
+        .filter(|param| param.bounds.iter().any(is_maybe_sized_bound))
+        .map(|param| &param.ident)
+        .collect::<HashSet<_>>();
+
+    if let Some(where_clause) = &generics.where_clause {
+        for predicate in &where_clause.predicates {
+            if let WherePredicate::Type(predicate_type) = predicate {
+                if predicate_type.bounds.iter().any(is_maybe_sized_bound) {
+                    result.extend(get_type_parameter(&predicate_type.bounded_ty));
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn is_unsized_type(ty: &Type) -> bool {
+    match ty {
+        Type::Group(type_group) => is_unsized_type(&type_group.elem),
+        Type::Paren(type_paren) => is_unsized_type(&type_paren.elem),
+        Type::Path(type_path) => type_path.qself.is_none() && type_path.path.is_ident("str"),
+        Type::Slice(_) | Type::TraitObject(_) => true,
+        _ => false,
+    }
+}
+
+/// Checks that the last field of a struct is `Sized`, which is required for implementing traits like `Clone` and
+/// `Default`. A last field typed with a `?Sized` type parameter is accepted if the user bounded that parameter for
+/// `trait_name`, since the bounds may require it to be `Sized`.
+pub fn check_sized(input: &DeriveInput, trait_name: &str) -> syn::Result<()> {
+    if let Data::Struct(data_struct) = &input.data {
+        if let Some(field) = data_struct.fields.iter().last() {
+            if is_unsized_type(&field.ty) {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    format!("Cannot derive `{}` on a type with an unsized field.", trait_name),
+                ));
+            }
+
+            if let Some(type_parameter) = get_type_parameter(&field.ty) {
+                let attributes = ContainerAttributes::parse(&input.attrs)?;
+
+                if get_maybe_sized_type_parameters(&input.generics).contains(type_parameter)
+                    && !attributes.perfect()
+                    && !attributes.bounds(trait_name).any(|predicate| {
+                        matches!(
+                            predicate,
+                            WherePredicate::Type(predicate_type)
+                                if get_type_parameter(&predicate_type.bounded_ty) == Some(type_parameter)
+                        )
+                    })
+                {
+                    return Err(syn::Error::new_spanned(
+                        &field.ty,
+                        format!(
+                            "Cannot derive `{0}` on a type whose last field may be unsized. Use `#[force_derive(perfect)]` \
+                             or `#[force_derive(bound({0} = \"{1}: {0}\"))]` to require a sized field.",
+                            trait_name, type_parameter,
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use proc_macro2::TokenStream;
+use syn::DeriveInput;
+
+pub fn derive_sync(input: DeriveInput) -> syn::Result<TokenStream> {
+    crate::marker_trait::derive_unsafe_marker_trait(input, "Sync", quote::quote!(::core::marker::Sync))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utilities;
+
+    #[test]
+    fn test_derive_sync() {
+        let test_cases = [
+            // Struct.
+            (
+                quote::quote! {
+                    #[force_derive(unsafe(Sync))]
+                    struct Foo<T> {
+                        foo: *const T,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    unsafe impl<T> ::core::marker::Sync for Foo<T> {}
+                },
+            ),
+            // Enum with generic constraints.
+            (
+                quote::quote! {
+                    #[force_derive(unsafe(Sync))]
+                    enum Foo<T>
+                    where
+                        u32: Copy,
+                    {
+                        Tuple1(*const T),
+                        Unit,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    unsafe impl<T> ::core::marker::Sync for Foo<T>
+                    where
+                        u32: Copy,
+                    {
+                    }
+                },
+            ),
+            // Custom bounds.
+            (
+                quote::quote! {
+                    #[force_derive(unsafe(Sync), bound(Sync = "T: Sync"))]
+                    struct Foo<T>(*const T);
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    unsafe impl<T> ::core::marker::Sync for Foo<T>
+                    where
+                        T: Sync
+                    {
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_sync(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_sync_without_acknowledgement() {
+        let test_cases = [
+            quote::quote! {
+                struct Foo<T>(*const T);
+            },
+            quote::quote! {
+                #[force_derive(unsafe(Send))]
+                struct Foo<T>(*const T);
+            },
+        ];
+
+        for input in test_cases {
+            assert!(super::derive_sync(utilities::parse_derive_input(input).unwrap()).is_err());
+        }
+    }
+}
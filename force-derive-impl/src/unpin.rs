@@ -0,0 +1,133 @@
+use crate::{attributes, utilities};
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use std::collections::HashSet;
+use syn::{DeriveInput, WherePredicate};
+
+pub fn derive_unpin(mut input: DeriveInput) -> syn::Result<TokenStream> {
+    // `PhantomData` fields are left out on purpose, so type parameters that only appear in `PhantomData` stay
+    // unbounded. This does not mean they are always `Unpin`, `PhantomData<PhantomPinned>` is not.
+    let mut visited = HashSet::new();
+
+    let predicates = attributes::included_field_types(&input.data, "Unpin")?
+        .into_iter()
+        .filter(|field_type| {
+            !utilities::is_phantom_data(field_type) && visited.insert(field_type.to_token_stream().to_string())
+        })
+        .map(|field_type| -> WherePredicate { syn::parse_quote!(#field_type: ::core::marker::Unpin) })
+        .collect::<Vec<_>>();
+
+    if !predicates.is_empty() {
+        input.generics.make_where_clause().predicates.extend(predicates);
+    }
+
+    crate::marker_trait::derive_marker_trait(input, "Unpin", quote::quote!(::core::marker::Unpin))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utilities;
+
+    #[test]
+    fn test_derive_unpin() {
+        let test_cases = [
+            // Empty struct.
+            (
+                quote::quote! {
+                    struct Foo {}
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::marker::Unpin for Foo {}
+                },
+            ),
+            // Struct with a phantom field.
+            (
+                quote::quote! {
+                    struct Foo<T> {
+                        foo: PhantomData<T>,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::marker::Unpin for Foo<T> {}
+                },
+            ),
+            // Struct with fields and generic constraints.
+            (
+                quote::quote! {
+                    struct Foo<T, U>
+                    where
+                        u32: Copy,
+                    {
+                        foo: PhantomData<T>,
+                        bar: Box<T>,
+                        baz: U,
+                        qux: U,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T, U> ::core::marker::Unpin for Foo<T, U>
+                    where
+                        u32: Copy,
+                        Box<T>: ::core::marker::Unpin,
+                        U: ::core::marker::Unpin
+                    {
+                    }
+                },
+            ),
+            // Tuple.
+            (
+                quote::quote! {
+                    struct Foo<T>(::core::marker::PhantomData<T>, u32);
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T> ::core::marker::Unpin for Foo<T>
+                    where
+                        u32: ::core::marker::Unpin
+                    {
+                    }
+                },
+            ),
+            // Unit.
+            (
+                quote::quote! {
+                    struct Foo;
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl ::core::marker::Unpin for Foo {}
+                },
+            ),
+            // Enum.
+            (
+                quote::quote! {
+                    enum Foo<T, U> {
+                        Struct1 { foo: PhantomData<T> },
+                        Tuple1(U),
+                        Unit,
+                    }
+                },
+                quote::quote! {
+                    #[automatically_derived]
+                    impl<T, U> ::core::marker::Unpin for Foo<T, U>
+                    where
+                        U: ::core::marker::Unpin
+                    {
+                    }
+                },
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                super::derive_unpin(utilities::parse_derive_input(input).unwrap())
+                    .unwrap()
+                    .to_string(),
+                expected.to_string(),
+            );
+        }
+    }
+}
@@ -1,8 +1,52 @@
-use proc_macro2::{Ident, TokenStream};
-use syn::DeriveInput;
+use proc_macro2::{Ident, Span, TokenStream};
+use syn::{DeriveInput, Field, Index, Member, Type};
+
+/// Returns an identifier for a variable introduced by the generated code. It uses `Span::mixed_site()`, so it never
+/// collides with variables in user code.
+pub fn get_local_variable(name: &str) -> Ident {
+    Ident::new(name, Span::mixed_site())
+}
 
 pub fn get_field_identifiers(n: usize) -> impl Iterator<Item = Ident> {
-    (0..n).map(move |i| quote::format_ident!("field_{}", i))
+    (0..n).map(move |i| quote::format_ident!("field_{}", i, span = Span::mixed_site()))
+}
+
+pub fn get_member(index: usize, field: &Field) -> Member {
+    field
+        .ident
+        .clone()
+        .map_or_else(|| Member::Unnamed(Index::from(index)), Member::Named)
+}
+
+pub fn get_tuple_patterns(n: usize, variables: &[(usize, Ident)]) -> Vec<TokenStream> {
+    let mut variables = variables.iter().peekable();
+
+    (0..n)
+        .map(|i| match variables.next_if(|(index, _)| *index == i) {
+            Some((_, variable)) => quote::quote!(#variable),
+            None => quote::quote!(_),
+        })
+        .collect()
+}
+
+pub fn get_rest_pattern(included: usize, total: usize) -> TokenStream {
+    if included < total {
+        quote::quote!(..)
+    } else {
+        TokenStream::new()
+    }
+}
+
+/// Returns whether `ty` is a path ending in `PhantomData`, like `PhantomData<T>` or `core::marker::PhantomData<T>`.
+pub fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Group(type_group) => is_phantom_data(&type_group.elem),
+        Type::Paren(type_paren) => is_phantom_data(&type_paren.elem),
+        Type::Path(type_path) => {
+            matches!(type_path.path.segments.last(), Some(segment) if segment.ident == "PhantomData")
+        }
+        _ => false,
+    }
 }
 
 pub fn parse_derive_input(input: TokenStream) -> Result<DeriveInput, TokenStream> {
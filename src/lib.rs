@@ -1,4 +1,18 @@
-pub use force_derive_impl::Clone;
+pub use force_derive_impl::{
+    Clone, Copy, Debug, Default, Display, Eq, From, Hash, Ord, PartialEq, PartialOrd, Send, Sync, Unpin,
+};
+
+/// Renamed derives that can be glob-imported without being ambiguous with the standard ones.
+pub mod prelude {
+    pub use crate::{
+        Clone as ForceClone, Copy as ForceCopy, Debug as ForceDebug, Default as ForceDefault, Display as ForceDisplay,
+        Eq as ForceEq, From as ForceFrom, Hash as ForceHash, Ord as ForceOrd, PartialEq as ForcePartialEq,
+        PartialOrd as ForcePartialOrd, Send as ForceSend, Sync as ForceSync, Unpin as ForceUnpin,
+    };
+}
+
+#[cfg(test)]
+extern crate self as force_derive;
 
 #[cfg(test)]
 mod tests;
@@ -1,19 +1,20 @@
 use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
 use std::rc::Rc;
 
 struct NotClone;
 
 // Struct.
 
-#[derive(force_derive_impl::Clone)]
+#[derive(force_derive::Clone)]
 struct StructClone0 {}
 
-#[derive(force_derive_impl::Clone)]
+#[derive(force_derive::Clone)]
 struct StructClone1<T> {
     foo: Rc<T>,
 }
 
-#[derive(force_derive_impl::Clone)]
+#[derive(force_derive::Clone)]
 struct StructClone2<T>
 where
     u32: Copy,
@@ -24,33 +25,33 @@ where
 
 // Tuple.
 
-#[derive(force_derive_impl::Clone)]
+#[derive(force_derive::Clone)]
 struct TupleClone0();
 
-#[derive(force_derive_impl::Clone)]
+#[derive(force_derive::Clone)]
 struct TupleClone1<T>(Rc<T>);
 
-#[derive(force_derive_impl::Clone)]
+#[derive(force_derive::Clone)]
 struct TupleClone2<T>(Rc<T>, PhantomData<T>)
 where
     u32: Copy;
 
 // Unit.
 
-#[derive(force_derive_impl::Clone)]
+#[derive(force_derive::Clone)]
 struct UnitClone;
 
 // Enum.
 
-#[derive(force_derive_impl::Clone)]
+#[derive(force_derive::Clone)]
 enum EnumClone0 {}
 
-#[derive(force_derive_impl::Clone)]
+#[derive(force_derive::Clone)]
 enum EnumClone1<T> {
     Tuple1(Rc<T>),
 }
 
-#[derive(force_derive_impl::Clone)]
+#[derive(force_derive::Clone)]
 enum EnumClone<T>
 where
     u32: Copy,
@@ -64,14 +65,57 @@ where
     Unit,
 }
 
+// Explicit bounds.
+
+#[derive(force_derive::Clone)]
+#[force_derive(bound(Clone = "T: Clone", Debug = ""))]
+struct BoundClone<T> {
+    foo: Vec<T>,
+    bar: PhantomData<T>,
+}
+
+// Perfect bounds.
+
+#[derive(force_derive::Clone)]
+#[force_derive(perfect)]
+struct PerfectClone<T> {
+    foo: Vec<T>,
+    bar: Rc<T>,
+    baz: PhantomData<T>,
+}
+
+// Clone from.
+
+#[allow(dead_code)]
+#[derive(force_derive::Clone)]
+struct BufferClone<T> {
+    data: Vec<u8>,
+    name: String,
+    marker: PhantomData<T>,
+}
+
+#[derive(force_derive::Clone)]
+enum BufferEnumClone {
+    Bytes(Vec<u8>),
+    Text { text: String },
+    Empty,
+}
+
 // Union.
 
 #[allow(dead_code)]
-#[derive(force_derive_impl::Clone, force_derive_impl::Copy)]
+#[derive(force_derive::Clone, force_derive::Copy)]
 union Union {
     foo: u32,
 }
 
+#[allow(dead_code)]
+#[derive(force_derive::Clone, force_derive::Copy)]
+union Bits<T> {
+    a: u32,
+    p: ManuallyDrop<PhantomData<T>>,
+}
+
 // Tests.
 
 static_assertions::assert_impl_all!(StructClone0: Clone);
@@ -84,7 +128,14 @@ static_assertions::assert_impl_all!(UnitClone: Clone);
 static_assertions::assert_impl_all!(EnumClone0: Clone);
 static_assertions::assert_impl_all!(EnumClone1<NotClone>: Clone);
 static_assertions::assert_impl_all!(EnumClone<NotClone>: Clone);
+static_assertions::assert_impl_all!(BufferClone<NotClone>: Clone);
+static_assertions::assert_impl_all!(BufferEnumClone: Clone);
 static_assertions::assert_impl_all!(Union: Clone);
+static_assertions::assert_impl_all!(Bits<NotClone>: Clone);
+static_assertions::assert_impl_all!(BoundClone<u32>: Clone);
+static_assertions::assert_not_impl_any!(BoundClone<NotClone>: Clone);
+static_assertions::assert_impl_all!(PerfectClone<u32>: Clone);
+static_assertions::assert_not_impl_any!(PerfectClone<NotClone>: Clone);
 
 fn clone<T>(value: &T) -> T
 where
@@ -169,3 +220,74 @@ fn test_clone() {
         EnumClone::<NotClone>::Unit,
     ));
 }
+
+#[test]
+fn test_clone_bound() {
+    assert!(matches!(
+        clone(&BoundClone::<u32> { foo: vec![2, 3], bar: PhantomData }),
+        BoundClone::<u32> { foo, bar: PhantomData } if foo == [2, 3],
+    ));
+}
+
+#[test]
+fn test_clone_perfect() {
+    assert!(matches!(
+        clone(&PerfectClone::<u32> {
+            foo: vec![2, 3],
+            bar: Rc::new(5),
+            baz: PhantomData,
+        }),
+        PerfectClone::<u32> { foo, bar, baz: PhantomData } if foo == [2, 3] && *bar == 5,
+    ));
+}
+
+#[test]
+fn test_clone_union() {
+    let value = clone(&Bits::<NotClone> { a: 7 });
+
+    assert_eq!(unsafe { value.a }, 7);
+}
+
+#[test]
+fn test_clone_from() {
+    let mut target = BufferClone::<NotClone> {
+        data: Vec::with_capacity(16),
+        name: String::with_capacity(16),
+        marker: PhantomData,
+    };
+
+    let data = target.data.as_ptr();
+    let name = target.name.as_ptr();
+
+    target.clone_from(&BufferClone {
+        data: vec![2, 3],
+        name: String::from("foo"),
+        marker: PhantomData,
+    });
+
+    assert_eq!(target.data, [2, 3]);
+    assert_eq!(target.name, "foo");
+    assert_eq!(target.data.as_ptr(), data);
+    assert_eq!(target.name.as_ptr(), name);
+
+    let mut target = BufferEnumClone::Bytes(Vec::with_capacity(16));
+
+    let data = match &target {
+        BufferEnumClone::Bytes(data) => data.as_ptr(),
+        _ => unreachable!(),
+    };
+
+    target.clone_from(&BufferEnumClone::Bytes(vec![2, 3]));
+
+    assert!(matches!(&target, BufferEnumClone::Bytes(bytes) if *bytes == [2, 3] && bytes.as_ptr() == data));
+
+    target.clone_from(&BufferEnumClone::Text {
+        text: String::from("foo"),
+    });
+
+    assert!(matches!(&target, BufferEnumClone::Text { text } if text == "foo"));
+
+    target.clone_from(&BufferEnumClone::Empty);
+
+    assert!(matches!(target, BufferEnumClone::Empty));
+}
@@ -1,20 +1,21 @@
 #![allow(dead_code)]
 
 use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
 
 struct NotCopy;
 
 // Struct.
 
-#[derive(force_derive_impl::Clone, force_derive_impl::Copy)]
+#[derive(force_derive::Clone, force_derive::Copy)]
 struct StructCopy0 {}
 
-#[derive(force_derive_impl::Clone, force_derive_impl::Copy)]
+#[derive(force_derive::Clone, force_derive::Copy)]
 struct StructCopy1<T> {
     foo: PhantomData<T>,
 }
 
-#[derive(force_derive_impl::Clone, force_derive_impl::Copy)]
+#[derive(force_derive::Clone, force_derive::Copy)]
 struct StructCopy2<T>
 where
     u32: Copy,
@@ -25,33 +26,33 @@ where
 
 // Tuple.
 
-#[derive(force_derive_impl::Clone, force_derive_impl::Copy)]
+#[derive(force_derive::Clone, force_derive::Copy)]
 struct TupleCopy0();
 
-#[derive(force_derive_impl::Clone, force_derive_impl::Copy)]
+#[derive(force_derive::Clone, force_derive::Copy)]
 struct TupleCopy1<T>(PhantomData<T>);
 
-#[derive(force_derive_impl::Clone, force_derive_impl::Copy)]
+#[derive(force_derive::Clone, force_derive::Copy)]
 struct TupleCopy2<T>(PhantomData<T>, PhantomData<T>)
 where
     u32: Copy;
 
 // Unit.
 
-#[derive(force_derive_impl::Clone, force_derive_impl::Copy)]
+#[derive(force_derive::Clone, force_derive::Copy)]
 struct UnitCopy;
 
 // Enum.
 
-#[derive(force_derive_impl::Clone, force_derive_impl::Copy)]
+#[derive(force_derive::Clone, force_derive::Copy)]
 enum EnumCopy0 {}
 
-#[derive(force_derive_impl::Clone, force_derive_impl::Copy)]
+#[derive(force_derive::Clone, force_derive::Copy)]
 enum EnumCopy1<T> {
     Tuple1(PhantomData<T>),
 }
 
-#[derive(force_derive_impl::Clone, force_derive_impl::Copy)]
+#[derive(force_derive::Clone, force_derive::Copy)]
 enum EnumCopy<T>
 where
     u32: Copy,
@@ -68,11 +69,17 @@ where
 // Union.
 
 #[allow(dead_code)]
-#[derive(force_derive_impl::Clone, force_derive_impl::Copy)]
+#[derive(force_derive::Clone, force_derive::Copy)]
 union Union {
     foo: u32,
 }
 
+#[derive(force_derive::Clone, force_derive::Copy)]
+union Bits<T> {
+    a: u32,
+    p: ManuallyDrop<PhantomData<T>>,
+}
+
 // Tests.
 
 static_assertions::assert_impl_all!(StructCopy0: Copy);
@@ -86,3 +93,12 @@ static_assertions::assert_impl_all!(EnumCopy0: Copy);
 static_assertions::assert_impl_all!(EnumCopy1<NotCopy>: Copy);
 static_assertions::assert_impl_all!(EnumCopy<NotCopy>: Copy);
 static_assertions::assert_impl_all!(Union: Copy);
+static_assertions::assert_impl_all!(Bits<NotCopy>: Copy);
+
+#[test]
+fn test_copy_union() {
+    let value = Bits::<NotCopy> { a: 7 };
+    let copied = value;
+
+    assert_eq!(unsafe { (value.a, copied.a) }, (7, 7));
+}
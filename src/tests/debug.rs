@@ -1,19 +1,19 @@
-use std::fmt::Debug;
+use std::fmt::{self, Debug, Formatter};
 use std::marker::PhantomData;
 
 struct NotDebug;
 
 // Struct.
 
-#[derive(force_derive_impl::Debug)]
+#[derive(force_derive::Debug)]
 struct StructDebug0 {}
 
-#[derive(force_derive_impl::Debug)]
+#[derive(force_derive::Debug)]
 struct StructDebug1<T> {
     foo: PhantomData<T>,
 }
 
-#[derive(force_derive_impl::Debug)]
+#[derive(force_derive::Debug)]
 struct StructDebug2<T>
 where
     u32: Copy,
@@ -24,33 +24,33 @@ where
 
 // Tuple.
 
-#[derive(force_derive_impl::Debug)]
+#[derive(force_derive::Debug)]
 struct TupleDebug0();
 
-#[derive(force_derive_impl::Debug)]
+#[derive(force_derive::Debug)]
 struct TupleDebug1<T>(PhantomData<T>);
 
-#[derive(force_derive_impl::Debug)]
+#[derive(force_derive::Debug)]
 struct TupleDebug2<T>(PhantomData<T>, PhantomData<T>)
 where
     u32: Copy;
 
 // Unit.
 
-#[derive(force_derive_impl::Debug)]
+#[derive(force_derive::Debug)]
 struct UnitDebug;
 
 // Enum.
 
-#[derive(force_derive_impl::Debug)]
+#[derive(force_derive::Debug)]
 enum EnumDebug0 {}
 
-#[derive(force_derive_impl::Debug)]
+#[derive(force_derive::Debug)]
 enum EnumDebug1<T> {
     Tuple1(PhantomData<T>),
 }
 
-#[derive(force_derive_impl::Debug)]
+#[derive(force_derive::Debug)]
 enum EnumDebug<T>
 where
     u32: Copy,
@@ -64,21 +64,202 @@ where
     Unit,
 }
 
+// Explicit bounds.
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+#[force_derive(bound(Debug = "T: Debug"))]
+struct BoundDebug<T> {
+    foo: Vec<T>,
+    bar: PhantomData<T>,
+}
+
 // Special identifiers.
 
 #[allow(dead_code)]
-#[derive(force_derive_impl::Debug)]
+#[derive(force_derive::Debug)]
 struct SpecialIdentifierStructDebug {
     f: u32,
 }
 
 #[allow(dead_code)]
 #[allow(non_camel_case_types)]
-#[derive(force_derive_impl::Debug)]
+#[derive(force_derive::Debug)]
 enum SpecialIdentifierEnumDebug {
     Struct { f: u32 },
 }
 
+// Unsized fields.
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+#[force_derive(perfect)]
+struct UnsizedDebug<H: ?Sized> {
+    len: u32,
+    header: H,
+}
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+struct StrDebug(u32, str);
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+#[force_derive(perfect)]
+struct SliceDebug<T> {
+    len: u32,
+    items: [T],
+}
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+struct DynDebug {
+    len: u32,
+    inner: dyn Debug,
+}
+
+// Field attributes.
+
+fn redacted<T: ?Sized>(_: &T, f: &mut Formatter) -> fmt::Result {
+    f.write_str("***")
+}
+
+fn hex(value: &u32, f: &mut Formatter) -> fmt::Result {
+    write!(f, "{value:#x}")
+}
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+struct FieldAttributesDebug<T> {
+    #[debug(rename = "user")]
+    name: String,
+    #[debug(with = redacted)]
+    password: String,
+    #[debug(with = hex)]
+    handle: u32,
+    #[debug(skip)]
+    marker: PhantomData<T>,
+}
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+enum FieldAttributesEnumDebug<T> {
+    Struct {
+        #[debug(skip)]
+        marker: PhantomData<T>,
+        #[debug(rename = "fd", with = hex)]
+        handle: u32,
+    },
+    Tuple(#[debug(with = redacted)] String, #[debug(skip)] PhantomData<T>),
+}
+
+struct DebugWith;
+
+impl DebugWith {
+    fn hex(value: &u32, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{value:#x}")
+    }
+}
+
+#[derive(force_derive::Debug)]
+struct UserDebugWith {
+    #[debug(with = DebugWith::hex)]
+    handle: u32,
+}
+
+// Non-exhaustive.
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+#[non_exhaustive]
+struct NonExhaustiveDebug {
+    foo: u32,
+}
+
+#[derive(force_derive::Debug)]
+#[non_exhaustive]
+struct NonExhaustiveDebug0 {}
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+#[non_exhaustive]
+struct NonExhaustiveTupleDebug(u32);
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+struct SkippedTupleDebug(#[debug(skip)] u32);
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+enum NonExhaustiveEnumDebug {
+    #[non_exhaustive]
+    Struct {
+        foo: u32,
+    },
+    #[non_exhaustive]
+    Tuple(u32),
+    Exhaustive(u32),
+}
+
+// Transparent.
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+#[debug(transparent)]
+struct TransparentDebug<T>(u64, PhantomData<T>);
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+#[debug(transparent)]
+struct TransparentStructDebug<T> {
+    marker: PhantomData<T>,
+    #[debug(with = hex)]
+    handle: u32,
+}
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+enum TransparentEnumDebug<T> {
+    #[debug(transparent)]
+    Struct {
+        marker: PhantomData<T>,
+        id: u64,
+    },
+    #[debug(transparent)]
+    Tuple(PhantomData<T>, String),
+    Opaque(u64, PhantomData<T>),
+}
+
+// Skip phantom.
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+#[debug(skip_phantom)]
+struct SkipPhantomDebug<T> {
+    id: u64,
+    marker: PhantomData<T>,
+}
+
+#[derive(force_derive::Debug)]
+#[debug(skip_phantom)]
+struct SkipPhantomTupleDebug<T>(std::marker::PhantomData<T>);
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+#[debug(skip_phantom)]
+enum SkipPhantomEnumDebug<T> {
+    Struct { marker: PhantomData<T>, id: u64 },
+    Tuple(u64, PhantomData<T>),
+}
+
+#[allow(dead_code)]
+#[derive(force_derive::Debug)]
+enum SkipPhantomVariantDebug<T> {
+    #[debug(skip_phantom)]
+    Omitted(u64, PhantomData<T>),
+    Printed(u64, PhantomData<T>),
+}
+
 // Tests.
 
 static_assertions::assert_impl_all!(StructDebug0: Debug);
@@ -91,10 +272,28 @@ static_assertions::assert_impl_all!(UnitDebug: Debug);
 static_assertions::assert_impl_all!(EnumDebug0: Debug);
 static_assertions::assert_impl_all!(EnumDebug1<NotDebug>: Debug);
 static_assertions::assert_impl_all!(EnumDebug<NotDebug>: Debug);
+static_assertions::assert_impl_all!(BoundDebug<u32>: Debug);
+static_assertions::assert_not_impl_any!(BoundDebug<NotDebug>: Debug);
+static_assertions::assert_impl_all!(UnsizedDebug<str>: Debug);
+static_assertions::assert_impl_all!(StrDebug: Debug);
+static_assertions::assert_impl_all!(SliceDebug<u32>: Debug);
+static_assertions::assert_not_impl_any!(SliceDebug<NotDebug>: Debug);
+static_assertions::assert_impl_all!(DynDebug: Debug);
+static_assertions::assert_impl_all!(FieldAttributesDebug<NotDebug>: Debug);
+static_assertions::assert_impl_all!(FieldAttributesEnumDebug<NotDebug>: Debug);
+static_assertions::assert_impl_all!(NonExhaustiveDebug: Debug);
+static_assertions::assert_impl_all!(NonExhaustiveEnumDebug: Debug);
+static_assertions::assert_impl_all!(TransparentDebug<NotDebug>: Debug);
+static_assertions::assert_impl_all!(TransparentStructDebug<NotDebug>: Debug);
+static_assertions::assert_impl_all!(TransparentEnumDebug<NotDebug>: Debug);
+static_assertions::assert_impl_all!(SkipPhantomDebug<NotDebug>: Debug);
+static_assertions::assert_impl_all!(SkipPhantomTupleDebug<NotDebug>: Debug);
+static_assertions::assert_impl_all!(SkipPhantomEnumDebug<NotDebug>: Debug);
+static_assertions::assert_impl_all!(SkipPhantomVariantDebug<NotDebug>: Debug);
 
 fn debug<T>(value: &T) -> String
 where
-    T: Debug,
+    T: Debug + ?Sized,
 {
     format!("{value:?}")
 }
@@ -172,6 +371,16 @@ fn test_debug() {
 
     assert_eq!(debug(&EnumDebug::<NotDebug>::Unit), "Unit");
 
+    // Explicit bounds.
+
+    assert_eq!(
+        debug(&BoundDebug::<u32> {
+            foo: vec![2, 3],
+            bar: PhantomData,
+        }),
+        "BoundDebug { foo: [2, 3], bar: PhantomData<u32> }",
+    );
+
     // Special identifiers.
 
     assert_eq!(
@@ -181,3 +390,139 @@ fn test_debug() {
 
     assert_eq!(debug(&SpecialIdentifierEnumDebug::Struct { f: 2 }), "Struct { f: 2 }");
 }
+
+#[test]
+fn test_debug_unsized() {
+    let slice: &UnsizedDebug<[u8]> = &UnsizedDebug { len: 2, header: [3, 5] };
+
+    assert_eq!(debug(slice), "UnsizedDebug { len: 2, header: [3, 5] }");
+
+    let trait_object: &UnsizedDebug<dyn Debug> = &UnsizedDebug { len: 2, header: "foo" };
+
+    assert_eq!(debug(trait_object), r#"UnsizedDebug { len: 2, header: "foo" }"#);
+}
+
+#[test]
+fn test_debug_field_attributes() {
+    assert_eq!(
+        debug(&FieldAttributesDebug::<NotDebug> {
+            name: String::from("foo"),
+            password: String::from("bar"),
+            handle: 255,
+            marker: PhantomData,
+        }),
+        r#"FieldAttributesDebug { user: "foo", password: ***, handle: 0xff, .. }"#,
+    );
+
+    assert_eq!(
+        debug(&FieldAttributesEnumDebug::<NotDebug>::Struct {
+            marker: PhantomData,
+            handle: 16,
+        }),
+        "Struct { fd: 0x10, .. }",
+    );
+
+    assert_eq!(
+        debug(&FieldAttributesEnumDebug::<NotDebug>::Tuple(
+            String::from("foo"),
+            PhantomData
+        )),
+        "Tuple(***, ..)",
+    );
+
+    assert_eq!(debug(&UserDebugWith { handle: 255 }), "UserDebugWith { handle: 0xff }");
+}
+
+#[test]
+fn test_debug_non_exhaustive() {
+    assert_eq!(
+        debug(&NonExhaustiveDebug { foo: 2 }),
+        "NonExhaustiveDebug { foo: 2, .. }"
+    );
+    assert_eq!(
+        format!("{:#?}", NonExhaustiveDebug { foo: 2 }),
+        "NonExhaustiveDebug {\n    foo: 2,\n    ..\n}"
+    );
+    assert_eq!(debug(&NonExhaustiveDebug0 {}), "NonExhaustiveDebug0 { .. }");
+    assert_eq!(debug(&NonExhaustiveTupleDebug(2)), "NonExhaustiveTupleDebug(2, ..)");
+    assert_eq!(debug(&SkippedTupleDebug(2)), "SkippedTupleDebug(..)");
+    assert_eq!(
+        debug(&NonExhaustiveEnumDebug::Struct { foo: 2 }),
+        "Struct { foo: 2, .. }"
+    );
+    assert_eq!(debug(&NonExhaustiveEnumDebug::Tuple(2)), "Tuple(2, ..)");
+    assert_eq!(debug(&NonExhaustiveEnumDebug::Exhaustive(2)), "Exhaustive(2)");
+}
+
+#[test]
+fn test_debug_transparent() {
+    assert_eq!(debug(&TransparentDebug::<NotDebug>(5, PhantomData)), "5");
+    assert_eq!(format!("{:x?}", TransparentDebug::<NotDebug>(255, PhantomData)), "ff");
+
+    assert_eq!(
+        debug(&TransparentStructDebug::<NotDebug> {
+            marker: PhantomData,
+            handle: 255,
+        }),
+        "0xff",
+    );
+
+    assert_eq!(
+        debug(&TransparentEnumDebug::<NotDebug>::Struct {
+            marker: PhantomData,
+            id: 5,
+        }),
+        "5",
+    );
+
+    assert_eq!(
+        debug(&TransparentEnumDebug::<NotDebug>::Tuple(
+            PhantomData,
+            String::from("foo")
+        )),
+        r#""foo""#,
+    );
+
+    assert_eq!(
+        debug(&TransparentEnumDebug::<NotDebug>::Opaque(5, PhantomData)),
+        "Opaque(5, PhantomData<force_derive::tests::debug::NotDebug>)",
+    );
+}
+
+#[test]
+fn test_debug_skip_phantom() {
+    assert_eq!(
+        debug(&SkipPhantomDebug::<NotDebug> {
+            id: 5,
+            marker: PhantomData,
+        }),
+        "SkipPhantomDebug { id: 5 }",
+    );
+
+    assert_eq!(
+        debug(&SkipPhantomTupleDebug::<NotDebug>(PhantomData)),
+        "SkipPhantomTupleDebug"
+    );
+
+    assert_eq!(
+        debug(&SkipPhantomEnumDebug::<NotDebug>::Struct {
+            marker: PhantomData,
+            id: 5,
+        }),
+        "Struct { id: 5 }",
+    );
+
+    assert_eq!(
+        debug(&SkipPhantomEnumDebug::<NotDebug>::Tuple(5, PhantomData)),
+        "Tuple(5)"
+    );
+    assert_eq!(
+        debug(&SkipPhantomVariantDebug::<NotDebug>::Omitted(5, PhantomData)),
+        "Omitted(5)"
+    );
+
+    assert_eq!(
+        debug(&SkipPhantomVariantDebug::<NotDebug>::Printed(5, PhantomData)),
+        "Printed(5, PhantomData<force_derive::tests::debug::NotDebug>)",
+    );
+}
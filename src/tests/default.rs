@@ -6,15 +6,15 @@ struct NotDefault;
 
 // Struct.
 
-#[derive(force_derive_impl::Default)]
+#[derive(force_derive::Default)]
 struct StructDefault0 {}
 
-#[derive(force_derive_impl::Default)]
+#[derive(force_derive::Default)]
 struct StructDefault1<T> {
     foo: Vec<T>,
 }
 
-#[derive(force_derive_impl::Default)]
+#[derive(force_derive::Default)]
 struct StructDefault2<T>
 where
     u32: Copy,
@@ -25,31 +25,31 @@ where
 
 // Tuple.
 
-#[derive(force_derive_impl::Default)]
+#[derive(force_derive::Default)]
 struct TupleDefault0();
 
-#[derive(force_derive_impl::Default)]
+#[derive(force_derive::Default)]
 struct TupleDefault1<T>(Vec<T>);
 
-#[derive(force_derive_impl::Default)]
+#[derive(force_derive::Default)]
 struct TupleDefault2<T>(Vec<T>, PhantomData<T>)
 where
     u32: Copy;
 
 // Unit.
 
-#[derive(force_derive_impl::Default)]
+#[derive(force_derive::Default)]
 struct UnitDefault;
 
 // Enum.
 
-#[derive(force_derive_impl::Default)]
+#[derive(force_derive::Default)]
 enum EnumDefault1<T> {
     #[default]
     Tuple1(Vec<T>),
 }
 
-#[derive(force_derive_impl::Default)]
+#[derive(force_derive::Default)]
 enum EnumDefaultStruct0<T>
 where
     u32: Copy,
@@ -69,7 +69,7 @@ where
     Unit,
 }
 
-#[derive(force_derive_impl::Default)]
+#[derive(force_derive::Default)]
 enum EnumDefaultStruct1<T>
 where
     u32: Copy,
@@ -89,7 +89,7 @@ where
     Unit,
 }
 
-#[derive(force_derive_impl::Default)]
+#[derive(force_derive::Default)]
 enum EnumDefaultStruct2<T>
 where
     u32: Copy,
@@ -109,7 +109,7 @@ where
     Unit,
 }
 
-#[derive(force_derive_impl::Default)]
+#[derive(force_derive::Default)]
 enum EnumDefaultTuple0<T>
 where
     u32: Copy,
@@ -129,7 +129,7 @@ where
     Unit,
 }
 
-#[derive(force_derive_impl::Default)]
+#[derive(force_derive::Default)]
 enum EnumDefaultTuple1<T>
 where
     u32: Copy,
@@ -149,7 +149,7 @@ where
     Unit,
 }
 
-#[derive(force_derive_impl::Default)]
+#[derive(force_derive::Default)]
 enum EnumDefaultTuple2<T>
 where
     u32: Copy,
@@ -169,7 +169,7 @@ where
     Unit,
 }
 
-#[derive(force_derive_impl::Default)]
+#[derive(force_derive::Default)]
 enum EnumDefaultUnit<T>
 where
     u32: Copy,
@@ -189,6 +189,48 @@ where
     Unit,
 }
 
+// Field expressions.
+
+#[derive(force_derive::Default)]
+struct Config<T> {
+    #[default = 8080]
+    port: u16,
+    #[default(String::from("localhost"))]
+    host: String,
+    handlers: Vec<T>,
+}
+
+#[derive(force_derive::Default)]
+struct TupleConfig<T>(#[default(3)] u32, PhantomData<T>);
+
+#[derive(force_derive::Default)]
+enum EnumConfig<T> {
+    Tuple(#[default(2)] u32),
+    #[default]
+    Struct {
+        #[default(vec![1, 2])]
+        foo: Vec<u32>,
+        bar: PhantomData<T>,
+    },
+}
+
+// Union.
+
+#[derive(force_derive::Default)]
+#[force_derive(perfect)]
+union UnionDefault<T: Copy> {
+    foo: u32,
+    #[default]
+    bar: T,
+}
+
+#[derive(force_derive::Default)]
+union UnionConfig {
+    #[default(0x3F80_0000)]
+    foo: u32,
+    bar: f32,
+}
+
 // Tests.
 
 static_assertions::assert_impl_all!(StructDefault0: Default);
@@ -206,7 +248,13 @@ static_assertions::assert_impl_all!(EnumDefaultTuple0::<NotDefault>: Default);
 static_assertions::assert_impl_all!(EnumDefaultTuple1::<NotDefault>: Default);
 static_assertions::assert_impl_all!(EnumDefaultTuple2::<NotDefault>: Default);
 static_assertions::assert_impl_all!(EnumDefaultUnit::<NotDefault>: Default);
+static_assertions::assert_impl_all!(Config::<NotDefault>: Default);
+static_assertions::assert_impl_all!(TupleConfig::<NotDefault>: Default);
+static_assertions::assert_impl_all!(EnumConfig::<NotDefault>: Default);
+static_assertions::assert_impl_all!(UnionDefault::<u64>: Default);
+static_assertions::assert_impl_all!(UnionConfig: Default);
 
+#[allow(clippy::default_constructed_unit_structs)]
 #[test]
 fn test_default() {
     // Struct.
@@ -283,3 +331,27 @@ fn test_default() {
         EnumDefaultUnit::<NotDefault>::Unit,
     ));
 }
+
+#[test]
+fn test_default_field_expression() {
+    assert!(matches!(
+        Config::<NotDefault>::default(),
+        Config::<NotDefault> { port: 8080, host, handlers } if host == "localhost" && handlers.is_empty(),
+    ));
+
+    assert!(matches!(
+        TupleConfig::<NotDefault>::default(),
+        TupleConfig::<NotDefault>(3, PhantomData)
+    ));
+
+    assert!(matches!(
+        EnumConfig::<NotDefault>::default(),
+        EnumConfig::<NotDefault>::Struct { foo, bar: PhantomData } if foo == [1, 2],
+    ));
+}
+
+#[test]
+fn test_default_union() {
+    assert_eq!(unsafe { UnionDefault::<u64>::default().bar }, 0);
+    assert_eq!(unsafe { UnionConfig::default().bar }, 1.0);
+}
@@ -0,0 +1,77 @@
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+struct NotDisplay;
+
+#[derive(force_derive::Display)]
+struct UnitDisplay;
+
+#[derive(force_derive::Display)]
+#[display("({x}, {y:.1})")]
+struct PointDisplay<T> {
+    x: i32,
+    y: f64,
+    marker: PhantomData<T>,
+}
+
+#[derive(force_derive::Display)]
+#[display("{name:>width$}")]
+struct PaddedDisplay {
+    name: &'static str,
+    width: usize,
+}
+
+#[derive(force_derive::Display)]
+enum Unit<T> {
+    Meter,
+    #[display("s")]
+    Second,
+    #[display("{value} {unit}")]
+    Scaled {
+        value: u32,
+        unit: &'static str,
+    },
+    #[display("phantom")]
+    Phantom(PhantomData<T>),
+}
+
+#[derive(force_derive::Display)]
+#[force_derive(bound(Display = "T: Display"))]
+#[display("<{value}>")]
+struct BoundDisplay<T> {
+    value: T,
+}
+
+// Tests.
+
+static_assertions::assert_impl_all!(UnitDisplay: Display);
+static_assertions::assert_impl_all!(PointDisplay<NotDisplay>: Display);
+static_assertions::assert_impl_all!(Unit<NotDisplay>: Display);
+static_assertions::assert_impl_all!(BoundDisplay<u32>: Display);
+static_assertions::assert_not_impl_any!(BoundDisplay<NotDisplay>: Display);
+
+#[test]
+fn test_display() {
+    assert_eq!(UnitDisplay.to_string(), "UnitDisplay");
+
+    assert_eq!(
+        PointDisplay::<NotDisplay> {
+            x: 2,
+            y: 3.25,
+            marker: PhantomData,
+        }
+        .to_string(),
+        "(2, 3.2)"
+    );
+
+    assert_eq!(PaddedDisplay { name: "foo", width: 5 }.to_string(), "  foo");
+    assert_eq!(BoundDisplay { value: 2 }.to_string(), "<2>");
+}
+
+#[test]
+fn test_display_enum() {
+    assert_eq!(Unit::<NotDisplay>::Meter.to_string(), "Meter");
+    assert_eq!(Unit::<NotDisplay>::Second.to_string(), "s");
+    assert_eq!(Unit::<NotDisplay>::Scaled { value: 5, unit: "km" }.to_string(), "5 km");
+    assert_eq!(Unit::<NotDisplay>::Phantom(PhantomData).to_string(), "phantom");
+}
@@ -6,15 +6,15 @@ struct NotEq;
 
 // Struct.
 
-#[derive(force_derive_impl::Eq, force_derive_impl::PartialEq)]
+#[derive(force_derive::Eq, force_derive::PartialEq)]
 struct StructCopy0 {}
 
-#[derive(force_derive_impl::Eq, force_derive_impl::PartialEq)]
+#[derive(force_derive::Eq, force_derive::PartialEq)]
 struct StructCopy1<T> {
     foo: PhantomData<T>,
 }
 
-#[derive(force_derive_impl::Eq, force_derive_impl::PartialEq)]
+#[derive(force_derive::Eq, force_derive::PartialEq)]
 struct StructCopy2<T>
 where
     u32: Copy,
@@ -25,33 +25,33 @@ where
 
 // Tuple.
 
-#[derive(force_derive_impl::Eq, force_derive_impl::PartialEq)]
+#[derive(force_derive::Eq, force_derive::PartialEq)]
 struct TupleCopy0();
 
-#[derive(force_derive_impl::Eq, force_derive_impl::PartialEq)]
+#[derive(force_derive::Eq, force_derive::PartialEq)]
 struct TupleCopy1<T>(PhantomData<T>);
 
-#[derive(force_derive_impl::Eq, force_derive_impl::PartialEq)]
+#[derive(force_derive::Eq, force_derive::PartialEq)]
 struct TupleCopy2<T>(PhantomData<T>, PhantomData<T>)
 where
     u32: Copy;
 
 // Unit.
 
-#[derive(force_derive_impl::Eq, force_derive_impl::PartialEq)]
+#[derive(force_derive::Eq, force_derive::PartialEq)]
 struct UnitCopy;
 
 // Enum.
 
-#[derive(force_derive_impl::Eq, force_derive_impl::PartialEq)]
+#[derive(force_derive::Eq, force_derive::PartialEq)]
 enum EnumCopy0 {}
 
-#[derive(force_derive_impl::Eq, force_derive_impl::PartialEq)]
+#[derive(force_derive::Eq, force_derive::PartialEq)]
 enum EnumCopy1<T> {
     Tuple1(PhantomData<T>),
 }
 
-#[derive(force_derive_impl::Eq, force_derive_impl::PartialEq)]
+#[derive(force_derive::Eq, force_derive::PartialEq)]
 enum EnumCopy<T>
 where
     u32: Copy,
@@ -65,6 +65,18 @@ where
     Unit,
 }
 
+// Perfect.
+
+struct Cache<T>(Vec<T>);
+
+#[derive(force_derive::Eq, force_derive::PartialEq)]
+#[force_derive(perfect)]
+struct PerfectSkipEq<T> {
+    foo: PhantomData<T>,
+    #[force_derive(skip)]
+    cache: Cache<T>,
+}
+
 // Tests.
 
 static_assertions::assert_impl_all!(StructCopy0: Eq);
@@ -77,3 +89,4 @@ static_assertions::assert_impl_all!(UnitCopy: Eq);
 static_assertions::assert_impl_all!(EnumCopy0: Eq);
 static_assertions::assert_impl_all!(EnumCopy1<NotEq>: Eq);
 static_assertions::assert_impl_all!(EnumCopy<NotEq>: Eq);
+static_assertions::assert_impl_all!(PerfectSkipEq<NotEq>: Eq);
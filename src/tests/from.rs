@@ -0,0 +1,113 @@
+use std::marker::PhantomData;
+
+struct NotDefault;
+
+#[derive(force_derive::Debug, force_derive::From, force_derive::PartialEq)]
+struct Meters<T>(f64, PhantomData<T>);
+
+#[derive(force_derive::Debug, force_derive::From, force_derive::PartialEq)]
+struct Tagged<T> {
+    value: u32,
+    tag: PhantomData<T>,
+    #[from(default)]
+    history: Vec<u32>,
+}
+
+#[derive(force_derive::Debug, force_derive::From, force_derive::PartialEq)]
+#[force_derive(perfect)]
+struct DefaultFrom<T> {
+    value: u32,
+    #[from(default)]
+    extra: T,
+}
+
+#[derive(force_derive::Debug, force_derive::From, force_derive::PartialEq)]
+enum Value<T> {
+    #[from]
+    Integer(i64),
+    #[from]
+    Text {
+        text: String,
+        marker: PhantomData<T>,
+    },
+    Other(i64),
+    Empty,
+}
+
+#[allow(dead_code)]
+#[derive(force_derive::From)]
+enum GenericValue<T> {
+    #[from]
+    List(Vec<T>),
+    #[from]
+    Optional(Option<T>),
+    #[from]
+    Integer(u32),
+}
+
+#[allow(dead_code)]
+#[derive(force_derive::From)]
+enum Error {
+    #[from]
+    Io(std::io::Error),
+    #[from]
+    Fmt(std::fmt::Error),
+}
+
+#[allow(dead_code)]
+#[derive(force_derive::From)]
+enum Bytes {
+    #[from]
+    Short([u8; 4]),
+    #[from]
+    Long([u8; 8]),
+}
+
+// Tests.
+
+static_assertions::assert_impl_all!(Meters<NotDefault>: From<f64>);
+static_assertions::assert_impl_all!(Tagged<NotDefault>: From<u32>);
+static_assertions::assert_impl_all!(DefaultFrom<u32>: From<u32>);
+static_assertions::assert_not_impl_any!(DefaultFrom<NotDefault>: From<u32>);
+static_assertions::assert_impl_all!(Value<NotDefault>: From<i64>, From<String>);
+static_assertions::assert_impl_all!(GenericValue<NotDefault>: From<Vec<NotDefault>>, From<Option<NotDefault>>, From<u32>);
+static_assertions::assert_impl_all!(Error: From<std::io::Error>, From<std::fmt::Error>);
+static_assertions::assert_impl_all!(Bytes: From<[u8; 4]>, From<[u8; 8]>);
+
+#[test]
+fn test_from_struct() {
+    assert_eq!(Meters::<NotDefault>::from(2.5), Meters(2.5, PhantomData));
+
+    assert_eq!(
+        Tagged::<NotDefault>::from(2),
+        Tagged {
+            value: 2,
+            tag: PhantomData,
+            history: Vec::new(),
+        },
+    );
+
+    assert_eq!(DefaultFrom::<u32>::from(2), DefaultFrom { value: 2, extra: 0 });
+}
+
+#[test]
+fn test_from_enum() {
+    assert_eq!(Value::<NotDefault>::from(2), Value::Integer(2));
+
+    assert_eq!(
+        Value::<NotDefault>::from(String::from("foo")),
+        Value::Text {
+            text: String::from("foo"),
+            marker: PhantomData,
+        },
+    );
+
+    assert_ne!(Value::<NotDefault>::from(2), Value::Other(2));
+    assert_ne!(Value::<NotDefault>::from(2), Value::Empty);
+}
+
+#[test]
+fn test_from_enum_with_same_named_types() {
+    assert!(matches!(Error::from(std::fmt::Error), Error::Fmt(_)));
+    assert!(matches!(Error::from(std::io::Error::other("foo")), Error::Io(_)));
+}
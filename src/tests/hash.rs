@@ -1,6 +1,9 @@
+use std::cell::Cell;
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::mem;
+use std::ptr;
+use std::rc::Rc;
 
 struct NotHash;
 
@@ -27,15 +30,15 @@ impl<T> Hash for ForceHash<T> {
 
 // Struct.
 
-#[derive(force_derive_impl::Hash)]
+#[derive(force_derive::Hash)]
 struct StructHash0 {}
 
-#[derive(force_derive_impl::Hash)]
+#[derive(force_derive::Hash)]
 struct StructHash1<T> {
     foo: ForceHash<T>,
 }
 
-#[derive(force_derive_impl::Hash)]
+#[derive(force_derive::Hash)]
 struct StructHash2<T>
 where
     u32: Copy,
@@ -46,33 +49,33 @@ where
 
 // Tuple.
 
-#[derive(force_derive_impl::Hash)]
+#[derive(force_derive::Hash)]
 struct TupleHash0();
 
-#[derive(force_derive_impl::Hash)]
+#[derive(force_derive::Hash)]
 struct TupleHash1<T>(ForceHash<T>);
 
-#[derive(force_derive_impl::Hash)]
+#[derive(force_derive::Hash)]
 struct TupleHash2<T>(ForceHash<T>, ForceHash<T>)
 where
     u32: Copy;
 
 // Unit.
 
-#[derive(force_derive_impl::Hash)]
+#[derive(force_derive::Hash)]
 struct UnitHash;
 
 // Enum.
 
-#[derive(force_derive_impl::Hash)]
+#[derive(force_derive::Hash)]
 enum EnumHash0 {}
 
-#[derive(force_derive_impl::Hash)]
+#[derive(force_derive::Hash)]
 enum EnumHash1<T> {
     Tuple1(ForceHash<T>),
 }
 
-#[derive(force_derive_impl::Hash)]
+#[derive(force_derive::Hash)]
 enum EnumHash<T>
 where
     u32: Copy,
@@ -88,16 +91,86 @@ where
 
 // Special identifiers.
 
-#[derive(force_derive_impl::Hash)]
+#[derive(force_derive::Hash)]
 struct SpecialIdentifierStructHash {
     state: u32,
 }
 
-#[derive(force_derive_impl::Hash)]
+#[derive(force_derive::Hash)]
 enum SpecialIdentifierEnumHash {
     Struct { state: u32 },
 }
 
+// Skipped fields.
+
+#[allow(dead_code)]
+#[derive(force_derive::Hash)]
+struct SkipStructHash<T> {
+    foo: u32,
+    #[force_derive(skip)]
+    cache: Cell<u32>,
+    #[force_derive(skip(Hash))]
+    bar: PhantomData<T>,
+}
+
+#[allow(dead_code)]
+#[derive(force_derive::Hash)]
+enum SkipEnumHash {
+    Struct {
+        foo: u32,
+        #[force_derive(skip)]
+        cache: Cell<u32>,
+    },
+    Tuple(#[force_derive(skip(PartialEq, Hash))] Cell<u32>, u32),
+}
+
+// Unsized fields.
+
+#[derive(force_derive::Hash)]
+#[force_derive(perfect)]
+struct UnsizedHash<H: ?Sized> {
+    len: u32,
+    header: H,
+}
+
+#[derive(force_derive::Hash)]
+struct StrHash(u32, str);
+
+#[derive(force_derive::Hash)]
+#[force_derive(perfect)]
+struct SliceHash<T> {
+    len: u32,
+    items: [T],
+}
+
+// Custom hashing.
+
+fn hash_bits<H: Hasher>(value: &f64, state: &mut H) {
+    Hash::hash(&value.to_bits(), state);
+}
+
+fn hash_address<T: ?Sized, H: Hasher>(value: &Rc<T>, state: &mut H) {
+    ptr::hash(Rc::as_ptr(value).cast::<()>(), state);
+}
+
+#[derive(force_derive::Hash)]
+#[force_derive(perfect)]
+struct WithHash<T> {
+    #[hash(with = hash_bits)]
+    value: f64,
+    #[hash(with = hash_address)]
+    callback: Rc<dyn Fn() -> T>,
+}
+
+#[derive(force_derive::Hash)]
+enum WithEnumHash {
+    Struct {
+        #[hash(with = hash_bits)]
+        value: f64,
+    },
+    Tuple(u32, #[hash(with = hash_bits)] f64),
+}
+
 // Tests.
 
 static_assertions::assert_impl_all!(StructHash0: Hash);
@@ -110,6 +183,11 @@ static_assertions::assert_impl_all!(UnitHash: Hash);
 static_assertions::assert_impl_all!(EnumHash0: Hash);
 static_assertions::assert_impl_all!(EnumHash1<NotHash>: Hash);
 static_assertions::assert_impl_all!(EnumHash<NotHash>: Hash);
+static_assertions::assert_impl_all!(UnsizedHash<str>: Hash);
+static_assertions::assert_impl_all!(StrHash: Hash);
+static_assertions::assert_impl_all!(SliceHash<u32>: Hash);
+static_assertions::assert_not_impl_any!(SliceHash<NotHash>: Hash);
+static_assertions::assert_impl_all!(WithHash<NotHash>: Hash);
 
 #[derive(PartialEq, Debug, Eq)]
 enum Operation {
@@ -191,7 +269,7 @@ impl Hasher for TestHasher {
     }
 }
 
-fn hash(value: &impl Hash) -> Vec<Operation> {
+fn hash(value: &(impl Hash + ?Sized)) -> Vec<Operation> {
     let mut hasher = TestHasher::default();
 
     value.hash(&mut hasher);
@@ -334,3 +412,67 @@ fn test_hash() {
     assert_eq!(hash(&SpecialIdentifierStructHash { state: 2 }), hash(&2_u32));
     assert_eq!(hash(&SpecialIdentifierEnumHash::Struct { state: 2 }), hash(&2_u32));
 }
+
+#[test]
+fn test_hash_skip() {
+    assert_eq!(
+        hash(&SkipStructHash::<NotHash> {
+            foo: 2,
+            cache: Cell::new(3),
+            bar: PhantomData,
+        }),
+        hash(&2_u32),
+    );
+
+    let enum_struct = SkipEnumHash::Struct {
+        foo: 2,
+        cache: Cell::new(3),
+    };
+
+    assert_eq!(
+        hash(&enum_struct),
+        hash_items(&[&mem::discriminant(&enum_struct), &2_u32]),
+    );
+
+    let enum_tuple = SkipEnumHash::Tuple(Cell::new(3), 2);
+
+    assert_eq!(
+        hash(&enum_tuple),
+        hash_items(&[&mem::discriminant(&enum_tuple), &2_u32])
+    );
+}
+
+#[test]
+fn test_hash_unsized() {
+    let value: &UnsizedHash<[u8]> = &UnsizedHash { len: 2, header: [3, 5] };
+
+    assert_eq!(hash(value), hash_items(&[&2_u32, &[3_u8, 5]]));
+}
+
+#[test]
+fn test_hash_with() {
+    let callback = Rc::new(|| NotHash) as Rc<dyn Fn() -> NotHash>;
+    let address = Rc::as_ptr(&callback).cast::<()>();
+
+    assert_eq!(
+        hash(&WithHash {
+            value: 2.0,
+            callback: Rc::clone(&callback),
+        }),
+        hash_items(&[&2.0_f64.to_bits(), &address]),
+    );
+
+    let enum_struct = WithEnumHash::Struct { value: 2.0 };
+
+    assert_eq!(
+        hash(&enum_struct),
+        hash_items(&[&mem::discriminant(&enum_struct), &2.0_f64.to_bits()]),
+    );
+
+    let enum_tuple = WithEnumHash::Tuple(3, 2.0);
+
+    assert_eq!(
+        hash(&enum_tuple),
+        hash_items(&[&mem::discriminant(&enum_tuple), &3_u32, &2.0_f64.to_bits()]),
+    );
+}
@@ -0,0 +1,124 @@
+#![allow(dead_code)]
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+// Fields named like the variables the derives generate.
+
+#[derive(
+    force_derive::Clone,
+    force_derive::Debug,
+    force_derive::Default,
+    force_derive::Display,
+    force_derive::Eq,
+    force_derive::Hash,
+    force_derive::Ord,
+    force_derive::PartialEq,
+    force_derive::PartialOrd,
+)]
+#[force_derive(perfect)]
+#[display("{f} {self_x} {other_x}")]
+struct StructHygiene<H> {
+    f: u32,
+    state: u32,
+    other: u32,
+    self_x: u32,
+    other_x: u32,
+    hasher: H,
+}
+
+#[derive(
+    force_derive::Clone,
+    force_derive::Debug,
+    force_derive::Default,
+    force_derive::Display,
+    force_derive::Eq,
+    force_derive::Hash,
+    force_derive::Ord,
+    force_derive::PartialEq,
+    force_derive::PartialOrd,
+)]
+#[force_derive(perfect)]
+enum EnumHygiene<H> {
+    #[default]
+    Unit,
+    #[display("tuple")]
+    Tuple(u32, H),
+    #[display("{f} {self_x} {x}")]
+    Struct {
+        foo: u32,
+        f: u32,
+        state: u32,
+        other: u32,
+        self_x: u32,
+        other_x: u32,
+        x: u32,
+    },
+}
+
+// Tests.
+
+fn hash<T: Hash>(value_to_hash: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    value_to_hash.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+#[test]
+fn test_hygiene_struct() {
+    let lhs = StructHygiene {
+        f: 1,
+        state: 2,
+        other: 3,
+        self_x: 4,
+        other_x: 5,
+        hasher: 6_u32,
+    };
+
+    let rhs = StructHygiene {
+        other: 4,
+        ..lhs.clone()
+    };
+
+    assert_eq!(
+        format!("{:?}", lhs),
+        "StructHygiene { f: 1, state: 2, other: 3, self_x: 4, other_x: 5, hasher: 6 }",
+    );
+
+    assert_eq!(lhs.to_string(), "1 4 5");
+    assert_eq!(lhs, lhs.clone());
+    assert_ne!(lhs, rhs);
+    assert!(lhs < rhs);
+    assert_eq!(hash(&lhs), hash(&lhs.clone()));
+}
+
+#[test]
+fn test_hygiene_enum() {
+    let value_1 = EnumHygiene::<u32>::Struct {
+        foo: 1,
+        f: 2,
+        state: 3,
+        other: 4,
+        self_x: 5,
+        other_x: 6,
+        x: 7,
+    };
+
+    let value_2 = EnumHygiene::Tuple(1, 2);
+
+    assert_eq!(
+        format!("{:?}", value_1),
+        "Struct { foo: 1, f: 2, state: 3, other: 4, self_x: 5, other_x: 6, x: 7 }",
+    );
+
+    assert_eq!(format!("{:?}", value_2), "Tuple(1, 2)");
+    assert_eq!(value_1.to_string(), "2 5 7");
+    assert_eq!(value_2.to_string(), "tuple");
+    assert_eq!(value_1, value_1.clone());
+    assert_ne!(value_1, value_2);
+    assert!(value_2 < value_1);
+    assert!(EnumHygiene::<u32>::default() < value_2);
+    assert_eq!(hash(&value_1), hash(&value_1.clone()));
+}
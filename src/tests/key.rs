@@ -0,0 +1,135 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+struct NotComparable;
+
+struct Tagged<T> {
+    id: u32,
+    marker: PhantomData<T>,
+}
+
+struct Person<T> {
+    name: String,
+    age: u32,
+    marker: PhantomData<T>,
+}
+
+// Key projections.
+
+#[derive(
+    force_derive::Eq, force_derive::Hash, force_derive::Ord, force_derive::PartialEq, force_derive::PartialOrd,
+)]
+struct KeyStruct {
+    #[force_derive(key = "|name| name.to_lowercase()")]
+    name: String,
+    rank: u32,
+}
+
+#[derive(
+    force_derive::Eq, force_derive::Hash, force_derive::Ord, force_derive::PartialEq, force_derive::PartialOrd,
+)]
+#[force_derive(perfect)]
+struct KeyTuple<T>(#[force_derive(key = "|value| value.id")] Tagged<T>);
+
+#[derive(
+    force_derive::Eq, force_derive::Hash, force_derive::Ord, force_derive::PartialEq, force_derive::PartialOrd,
+)]
+enum KeyEnum {
+    Struct {
+        #[force_derive(key = "|name| name.len()")]
+        name: Box<str>,
+    },
+    Tuple(#[force_derive(key = "|value| value.abs()")] i32),
+}
+
+#[derive(
+    force_derive::Eq, force_derive::Hash, force_derive::Ord, force_derive::PartialEq, force_derive::PartialOrd,
+)]
+struct KeyBorrowed<T> {
+    #[force_derive(key = "|person| &person.name")]
+    person: Person<T>,
+    #[force_derive(key = "|label| label.as_str()")]
+    label: String,
+}
+
+// Tests.
+
+static_assertions::assert_impl_all!(KeyStruct: Ord, Hash);
+static_assertions::assert_impl_all!(KeyTuple<NotComparable>: Ord, Hash);
+static_assertions::assert_impl_all!(KeyEnum: Ord, Hash);
+static_assertions::assert_impl_all!(KeyBorrowed<NotComparable>: Ord, Hash);
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    value.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+#[test]
+fn test_key_struct() {
+    let value = |name: &str, rank| KeyStruct {
+        name: name.to_string(),
+        rank,
+    };
+
+    assert!(value("Foo", 2) == value("fOO", 2));
+    assert!(value("Foo", 2) != value("Bar", 2));
+    assert_eq!(hash(&value("Foo", 2)), hash(&value("fOO", 2)));
+    assert_eq!(value("Foo", 2).cmp(&value("fOO", 2)), Ordering::Equal);
+    assert_eq!(value("a", 3).cmp(&value("B", 2)), Ordering::Less);
+    assert_eq!(value("a", 3).partial_cmp(&value("A", 2)), Some(Ordering::Greater));
+}
+
+#[test]
+fn test_key_tuple() {
+    let value = |id| {
+        KeyTuple::<NotComparable>(Tagged {
+            id,
+            marker: PhantomData,
+        })
+    };
+
+    assert!(value(2) == value(2));
+    assert!(value(2) != value(3));
+    assert_eq!(hash(&value(2)), hash(&value(2)));
+    assert_eq!(value(2).cmp(&value(3)), Ordering::Less);
+}
+
+#[test]
+fn test_key_enum() {
+    let enum_struct = |name: &str| KeyEnum::Struct { name: name.into() };
+
+    assert!(enum_struct("foo") == enum_struct("bar"));
+    assert!(enum_struct("foo") != enum_struct("fo"));
+    assert_eq!(hash(&enum_struct("foo")), hash(&enum_struct("bar")));
+    assert_eq!(enum_struct("fo").cmp(&enum_struct("bar")), Ordering::Less);
+
+    assert!(KeyEnum::Tuple(2) == KeyEnum::Tuple(-2));
+    assert_eq!(hash(&KeyEnum::Tuple(2)), hash(&KeyEnum::Tuple(-2)));
+    assert_eq!(KeyEnum::Tuple(-3).cmp(&KeyEnum::Tuple(2)), Ordering::Greater);
+    assert_eq!(enum_struct("foo").cmp(&KeyEnum::Tuple(0)), Ordering::Less);
+}
+
+#[test]
+fn test_key_borrowed() {
+    let value = |name: &str, age, label: &str| KeyBorrowed::<NotComparable> {
+        person: Person {
+            name: name.to_string(),
+            age,
+            marker: PhantomData,
+        },
+        label: label.to_string(),
+    };
+
+    assert!(value("foo", 2, "a") == value("foo", 3, "a"));
+    assert!(value("foo", 2, "a") != value("bar", 2, "a"));
+    assert!(value("foo", 2, "a") != value("foo", 2, "b"));
+    assert_eq!(hash(&value("foo", 2, "a")), hash(&value("foo", 3, "a")));
+    assert_eq!(value("bar", 3, "b").cmp(&value("foo", 2, "a")), Ordering::Less);
+    assert_eq!(value("foo", 3, "a").cmp(&value("foo", 2, "b")), Ordering::Less);
+    assert_eq!(value("foo", 2, "a").person.age, 2);
+}
@@ -2,6 +2,17 @@ mod clone;
 mod copy;
 mod debug;
 mod default;
+mod display;
 mod eq;
+mod from;
 mod hash;
+mod hygiene;
+mod key;
+mod ord;
 mod partial_eq;
+mod partial_ord;
+mod prelude;
+mod send;
+mod sync;
+mod ui;
+mod unpin;
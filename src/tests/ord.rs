@@ -0,0 +1,177 @@
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+
+struct NotOrd;
+
+// Struct.
+
+#[derive(force_derive::Eq, force_derive::Ord, force_derive::PartialEq, force_derive::PartialOrd)]
+struct StructOrd0 {}
+
+#[derive(force_derive::Eq, force_derive::Ord, force_derive::PartialEq, force_derive::PartialOrd)]
+struct StructOrd1<T> {
+    foo: PhantomData<T>,
+}
+
+#[derive(force_derive::Eq, force_derive::Ord, force_derive::PartialEq, force_derive::PartialOrd)]
+struct StructOrd2<T>
+where
+    u32: Copy,
+{
+    foo: u32,
+    bar: PhantomData<T>,
+    baz: u32,
+}
+
+// Tuple.
+
+#[derive(force_derive::Eq, force_derive::Ord, force_derive::PartialEq, force_derive::PartialOrd)]
+struct TupleOrd0();
+
+#[derive(force_derive::Eq, force_derive::Ord, force_derive::PartialEq, force_derive::PartialOrd)]
+struct TupleOrd1<T>(PhantomData<T>);
+
+#[derive(force_derive::Eq, force_derive::Ord, force_derive::PartialEq, force_derive::PartialOrd)]
+struct TupleOrd2<T>(u32, PhantomData<T>, u32)
+where
+    u32: Copy;
+
+// Unit.
+
+#[derive(force_derive::Eq, force_derive::Ord, force_derive::PartialEq, force_derive::PartialOrd)]
+struct UnitOrd;
+
+// Enum.
+
+#[derive(force_derive::Eq, force_derive::Ord, force_derive::PartialEq, force_derive::PartialOrd)]
+enum EnumOrd0 {}
+
+#[derive(force_derive::Eq, force_derive::Ord, force_derive::PartialEq, force_derive::PartialOrd)]
+enum EnumOrd1<T> {
+    Tuple1(PhantomData<T>),
+}
+
+#[derive(force_derive::Eq, force_derive::Ord, force_derive::PartialEq, force_derive::PartialOrd)]
+enum EnumOrd<T>
+where
+    u32: Copy,
+{
+    Struct0 {},
+    Struct1 { foo: PhantomData<T> },
+    Struct2 { foo: PhantomData<T>, bar: u32 },
+    Tuple0(),
+    Tuple1(PhantomData<T>),
+    Tuple2(PhantomData<T>, u32),
+    Unit,
+}
+
+// Tests.
+
+static_assertions::assert_impl_all!(StructOrd0: Ord);
+static_assertions::assert_impl_all!(StructOrd1<NotOrd>: Ord);
+static_assertions::assert_impl_all!(StructOrd2<NotOrd>: Ord);
+static_assertions::assert_impl_all!(TupleOrd0: Ord);
+static_assertions::assert_impl_all!(TupleOrd1<NotOrd>: Ord);
+static_assertions::assert_impl_all!(TupleOrd2<NotOrd>: Ord);
+static_assertions::assert_impl_all!(UnitOrd: Ord);
+static_assertions::assert_impl_all!(EnumOrd0: Ord);
+static_assertions::assert_impl_all!(EnumOrd1<NotOrd>: Ord);
+static_assertions::assert_impl_all!(EnumOrd<NotOrd>: Ord);
+
+fn cmp<T>(lhs: &T, rhs: &T) -> Ordering
+where
+    T: Ord,
+{
+    Ord::cmp(lhs, rhs)
+}
+
+#[test]
+fn test_ord_struct() {
+    assert_eq!(cmp(&StructOrd0 {}, &StructOrd0 {}), Ordering::Equal);
+
+    assert_eq!(
+        cmp(
+            &StructOrd1::<NotOrd> { foo: PhantomData },
+            &StructOrd1::<NotOrd> { foo: PhantomData },
+        ),
+        Ordering::Equal,
+    );
+
+    let value = |foo, baz| StructOrd2::<NotOrd> {
+        foo,
+        bar: PhantomData,
+        baz,
+    };
+
+    assert_eq!(cmp(&value(2, 2), &value(2, 2)), Ordering::Equal);
+    assert_eq!(cmp(&value(2, 3), &value(3, 2)), Ordering::Less);
+    assert_eq!(cmp(&value(3, 2), &value(2, 3)), Ordering::Greater);
+    assert_eq!(cmp(&value(2, 2), &value(2, 3)), Ordering::Less);
+    assert_eq!(cmp(&value(2, 3), &value(2, 2)), Ordering::Greater);
+}
+
+#[test]
+fn test_ord_tuple() {
+    assert_eq!(cmp(&TupleOrd0(), &TupleOrd0()), Ordering::Equal);
+
+    assert_eq!(
+        cmp(&TupleOrd1::<NotOrd>(PhantomData), &TupleOrd1::<NotOrd>(PhantomData)),
+        Ordering::Equal,
+    );
+
+    let value = |foo, baz| TupleOrd2::<NotOrd>(foo, PhantomData, baz);
+
+    assert_eq!(cmp(&value(2, 2), &value(2, 2)), Ordering::Equal);
+    assert_eq!(cmp(&value(2, 3), &value(3, 2)), Ordering::Less);
+    assert_eq!(cmp(&value(3, 2), &value(2, 3)), Ordering::Greater);
+    assert_eq!(cmp(&value(2, 2), &value(2, 3)), Ordering::Less);
+    assert_eq!(cmp(&value(2, 3), &value(2, 2)), Ordering::Greater);
+}
+
+#[test]
+fn test_ord_unit() {
+    assert_eq!(cmp(&UnitOrd, &UnitOrd), Ordering::Equal);
+}
+
+#[test]
+fn test_ord_enum_1() {
+    assert_eq!(
+        cmp(
+            &EnumOrd1::<NotOrd>::Tuple1(PhantomData),
+            &EnumOrd1::<NotOrd>::Tuple1(PhantomData),
+        ),
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn test_ord_enum() {
+    let struct_0 = || EnumOrd::<NotOrd>::Struct0 {};
+    let struct_1 = || EnumOrd::<NotOrd>::Struct1 { foo: PhantomData };
+
+    let struct_2_2 = || EnumOrd::<NotOrd>::Struct2 {
+        foo: PhantomData,
+        bar: 2,
+    };
+
+    let struct_2_3 = || EnumOrd::<NotOrd>::Struct2 {
+        foo: PhantomData,
+        bar: 3,
+    };
+
+    let tuple_0 = EnumOrd::<NotOrd>::Tuple0;
+    let tuple_1 = || EnumOrd::<NotOrd>::Tuple1(PhantomData);
+    let tuple_2_2 = || EnumOrd::<NotOrd>::Tuple2(PhantomData, 2);
+    let tuple_2_3 = || EnumOrd::<NotOrd>::Tuple2(PhantomData, 3);
+    let unit = || EnumOrd::<NotOrd>::Unit;
+
+    let all_values = [
+        struct_0, struct_1, struct_2_2, struct_2_3, tuple_0, tuple_1, tuple_2_2, tuple_2_3, unit,
+    ];
+
+    for (i, lhs) in all_values.iter().enumerate() {
+        for (j, rhs) in all_values.iter().enumerate() {
+            assert_eq!(cmp(&lhs(), &rhs()), i.cmp(&j));
+        }
+    }
+}
@@ -1,18 +1,20 @@
+use std::cell::Cell;
 use std::marker::PhantomData;
+use std::rc::Rc;
 
 struct NotPartialEq;
 
 // Struct.
 
-#[derive(force_derive_impl::PartialEq)]
+#[derive(force_derive::PartialEq)]
 struct StructPartialEq0 {}
 
-#[derive(force_derive_impl::PartialEq)]
+#[derive(force_derive::PartialEq)]
 struct StructPartialEq1<T> {
     foo: PhantomData<T>,
 }
 
-#[derive(force_derive_impl::PartialEq)]
+#[derive(force_derive::PartialEq)]
 struct StructPartialEq2<T>
 where
     u32: Copy,
@@ -23,33 +25,33 @@ where
 
 // Tuple.
 
-#[derive(force_derive_impl::PartialEq)]
+#[derive(force_derive::PartialEq)]
 struct TuplePartialEq0();
 
-#[derive(force_derive_impl::PartialEq)]
+#[derive(force_derive::PartialEq)]
 struct TuplePartialEq1<T>(PhantomData<T>);
 
-#[derive(force_derive_impl::PartialEq)]
+#[derive(force_derive::PartialEq)]
 struct TuplePartialEq2<T>(PhantomData<T>, u32)
 where
     u32: Copy;
 
 // Unit.
 
-#[derive(force_derive_impl::PartialEq)]
+#[derive(force_derive::PartialEq)]
 struct UnitPartialEq;
 
 // Enum.
 
-#[derive(force_derive_impl::PartialEq)]
+#[derive(force_derive::PartialEq)]
 enum EnumPartialEq0 {}
 
-#[derive(force_derive_impl::PartialEq)]
+#[derive(force_derive::PartialEq)]
 enum EnumPartialEq1<T> {
     Tuple1(PhantomData<T>),
 }
 
-#[derive(force_derive_impl::PartialEq)]
+#[derive(force_derive::PartialEq)]
 enum EnumPartialEq<T>
 where
     u32: Copy,
@@ -78,23 +80,110 @@ static_assertions::assert_impl_all!(EnumPartialEq<NotPartialEq>: PartialEq);
 
 fn partial_eq<T>(lhs: &T, rhs: &T) -> (bool, bool)
 where
-    T: PartialEq,
+    T: PartialEq + ?Sized,
 {
     (PartialEq::eq(lhs, rhs), PartialEq::ne(lhs, rhs))
 }
 
 // Special identifiers.
 
-#[derive(force_derive_impl::PartialEq)]
+#[derive(force_derive::PartialEq)]
 struct SpecialIdentifierStructPartialEq {
     other: u32,
 }
 
-#[derive(force_derive_impl::PartialEq)]
+#[derive(force_derive::PartialEq)]
 enum SpecialIdentifierEnumPartialEq {
     Struct { other: u32 },
 }
 
+// Perfect bounds.
+
+#[allow(dead_code)]
+#[derive(force_derive::PartialEq)]
+#[force_derive(perfect)]
+struct PerfectPartialEq<T> {
+    foo: Vec<T>,
+    #[force_derive(skip)]
+    bar: Option<Box<dyn Fn() -> T>>,
+}
+
+static_assertions::assert_impl_all!(PerfectPartialEq<u32>: PartialEq);
+static_assertions::assert_not_impl_any!(PerfectPartialEq<NotPartialEq>: PartialEq);
+
+// Unsized fields.
+
+#[derive(force_derive::PartialEq)]
+#[force_derive(perfect)]
+struct UnsizedPartialEq<H: ?Sized> {
+    len: u32,
+    header: H,
+}
+
+#[derive(force_derive::PartialEq)]
+struct StrPartialEq(u32, str);
+
+#[derive(force_derive::PartialEq)]
+#[force_derive(perfect)]
+struct SlicePartialEq<T> {
+    len: u32,
+    items: [T],
+}
+
+static_assertions::assert_impl_all!(UnsizedPartialEq<str>: PartialEq);
+static_assertions::assert_impl_all!(StrPartialEq: PartialEq);
+static_assertions::assert_impl_all!(SlicePartialEq<u32>: PartialEq);
+static_assertions::assert_not_impl_any!(SlicePartialEq<NotPartialEq>: PartialEq);
+
+// Skipped fields.
+
+#[allow(dead_code)]
+#[derive(force_derive::PartialEq)]
+struct SkipStructPartialEq<T> {
+    foo: u32,
+    #[force_derive(skip)]
+    cache: Cell<u32>,
+    #[force_derive(skip(PartialEq))]
+    bar: PhantomData<T>,
+}
+
+#[allow(dead_code)]
+#[derive(force_derive::PartialEq)]
+enum SkipEnumPartialEq {
+    Struct {
+        foo: u32,
+        #[force_derive(skip)]
+        cache: Cell<u32>,
+    },
+    Tuple(#[force_derive(skip)] Cell<u32>, u32),
+}
+
+// Custom comparison.
+
+fn approx_eq(lhs: &f64, rhs: &f64) -> bool {
+    (lhs - rhs).abs() < 0.001
+}
+
+#[derive(force_derive::PartialEq)]
+#[force_derive(perfect)]
+struct WithPartialEq<T> {
+    #[partial_eq(with = approx_eq)]
+    value: f64,
+    #[partial_eq(with = Rc::ptr_eq)]
+    callback: Rc<dyn Fn() -> T>,
+}
+
+#[derive(force_derive::PartialEq)]
+enum WithEnumPartialEq {
+    Struct {
+        #[partial_eq(with = approx_eq)]
+        value: f64,
+    },
+    Tuple(u32, #[partial_eq(with = approx_eq)] f64),
+}
+
+static_assertions::assert_impl_all!(WithPartialEq<NotPartialEq>: PartialEq);
+
 #[test]
 fn test_partial_eq_struct() {
     assert_eq!(partial_eq(&StructPartialEq0 {}, &StructPartialEq0 {}), (true, false));
@@ -227,3 +316,84 @@ fn test_partial_eq_special_identifiers() {
     assert_eq!(partial_eq(&enum_2(), &enum_2()), (true, false));
     assert_eq!(partial_eq(&enum_2(), &enum_3()), (false, true));
 }
+
+#[test]
+fn test_partial_eq_skip() {
+    let struct_value = |foo, cache| SkipStructPartialEq::<NotPartialEq> {
+        foo,
+        cache: Cell::new(cache),
+        bar: PhantomData,
+    };
+
+    assert_eq!(partial_eq(&struct_value(2, 5), &struct_value(2, 7)), (true, false));
+    assert_eq!(partial_eq(&struct_value(2, 5), &struct_value(3, 5)), (false, true));
+
+    let enum_struct = |foo, cache| SkipEnumPartialEq::Struct {
+        foo,
+        cache: Cell::new(cache),
+    };
+
+    let enum_tuple = |foo, cache| SkipEnumPartialEq::Tuple(Cell::new(cache), foo);
+
+    assert_eq!(partial_eq(&enum_struct(2, 5), &enum_struct(2, 7)), (true, false));
+    assert_eq!(partial_eq(&enum_struct(2, 5), &enum_struct(3, 5)), (false, true));
+    assert_eq!(partial_eq(&enum_tuple(2, 5), &enum_tuple(2, 7)), (true, false));
+    assert_eq!(partial_eq(&enum_tuple(2, 5), &enum_tuple(3, 5)), (false, true));
+    assert_eq!(partial_eq(&enum_struct(2, 5), &enum_tuple(2, 5)), (false, true));
+}
+
+#[test]
+fn test_partial_eq_perfect() {
+    let value = |foo| PerfectPartialEq::<u32> { foo, bar: None };
+
+    assert_eq!(partial_eq(&value(vec![2, 3]), &value(vec![2, 3])), (true, false));
+    assert_eq!(partial_eq(&value(vec![2, 3]), &value(vec![2])), (false, true));
+}
+
+#[test]
+fn test_partial_eq_unsized() {
+    let value: &UnsizedPartialEq<[u8]> = &UnsizedPartialEq { len: 2, header: [3, 5] };
+    let equal: &UnsizedPartialEq<[u8]> = &UnsizedPartialEq { len: 2, header: [3, 5] };
+    let different_len: &UnsizedPartialEq<[u8]> = &UnsizedPartialEq { len: 3, header: [3, 5] };
+    let different_header: &UnsizedPartialEq<[u8]> = &UnsizedPartialEq {
+        len: 2,
+        header: [3, 5, 7],
+    };
+
+    assert_eq!(partial_eq(value, equal), (true, false));
+    assert_eq!(partial_eq(value, different_len), (false, true));
+    assert_eq!(partial_eq(value, different_header), (false, true));
+}
+
+#[test]
+fn test_partial_eq_with() {
+    let callback = Rc::new(|| NotPartialEq) as Rc<dyn Fn() -> NotPartialEq>;
+    let other_callback = Rc::new(|| NotPartialEq) as Rc<dyn Fn() -> NotPartialEq>;
+
+    let value = |value, callback: &Rc<dyn Fn() -> NotPartialEq>| WithPartialEq {
+        value,
+        callback: Rc::clone(callback),
+    };
+
+    assert_eq!(
+        partial_eq(&value(2.0, &callback), &value(2.0001, &callback)),
+        (true, false)
+    );
+    assert_eq!(
+        partial_eq(&value(2.0, &callback), &value(2.1, &callback)),
+        (false, true)
+    );
+    assert_eq!(
+        partial_eq(&value(2.0, &callback), &value(2.0, &other_callback)),
+        (false, true)
+    );
+
+    let enum_struct = |value| WithEnumPartialEq::Struct { value };
+    let enum_tuple = |foo, value| WithEnumPartialEq::Tuple(foo, value);
+
+    assert_eq!(partial_eq(&enum_struct(2.0), &enum_struct(2.0001)), (true, false));
+    assert_eq!(partial_eq(&enum_struct(2.0), &enum_struct(2.1)), (false, true));
+    assert_eq!(partial_eq(&enum_tuple(2, 2.0), &enum_tuple(2, 2.0001)), (true, false));
+    assert_eq!(partial_eq(&enum_tuple(2, 2.0), &enum_tuple(3, 2.0)), (false, true));
+    assert_eq!(partial_eq(&enum_struct(2.0), &enum_tuple(2, 2.0)), (false, true));
+}
@@ -0,0 +1,233 @@
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+
+struct NotPartialOrd;
+
+// Struct.
+
+#[derive(force_derive::PartialEq, force_derive::PartialOrd)]
+struct StructPartialOrd0 {}
+
+#[derive(force_derive::PartialEq, force_derive::PartialOrd)]
+struct StructPartialOrd1<T> {
+    foo: PhantomData<T>,
+}
+
+#[derive(force_derive::PartialEq, force_derive::PartialOrd)]
+struct StructPartialOrd2<T>
+where
+    u32: Copy,
+{
+    foo: u32,
+    bar: PhantomData<T>,
+    baz: f64,
+}
+
+// Tuple.
+
+#[derive(force_derive::PartialEq, force_derive::PartialOrd)]
+struct TuplePartialOrd0();
+
+#[derive(force_derive::PartialEq, force_derive::PartialOrd)]
+struct TuplePartialOrd1<T>(PhantomData<T>);
+
+#[derive(force_derive::PartialEq, force_derive::PartialOrd)]
+struct TuplePartialOrd2<T>(u32, PhantomData<T>, f64)
+where
+    u32: Copy;
+
+// Unit.
+
+#[derive(force_derive::PartialEq, force_derive::PartialOrd)]
+struct UnitPartialOrd;
+
+// Enum.
+
+#[derive(force_derive::PartialEq, force_derive::PartialOrd)]
+enum EnumPartialOrd0 {}
+
+#[derive(force_derive::PartialEq, force_derive::PartialOrd)]
+enum EnumPartialOrd1<T> {
+    Tuple1(PhantomData<T>),
+}
+
+#[derive(force_derive::PartialEq, force_derive::PartialOrd)]
+enum EnumPartialOrd<T>
+where
+    u32: Copy,
+{
+    Struct0 {},
+    Struct1 { foo: PhantomData<T> },
+    Struct2 { foo: PhantomData<T>, bar: u32 },
+    Tuple0(),
+    Tuple1(PhantomData<T>),
+    Tuple2(PhantomData<T>, u32),
+    Unit,
+}
+
+// Special identifiers.
+
+#[derive(force_derive::PartialEq, force_derive::PartialOrd)]
+struct SpecialIdentifierStructPartialOrd {
+    other: u32,
+    ordering: u32,
+}
+
+#[derive(force_derive::PartialEq, force_derive::PartialOrd)]
+enum SpecialIdentifierEnumPartialOrd {
+    Struct { other: u32, ordering: u32 },
+    Tuple(u32),
+}
+
+// Tests.
+
+static_assertions::assert_impl_all!(StructPartialOrd0: PartialOrd);
+static_assertions::assert_impl_all!(StructPartialOrd1<NotPartialOrd>: PartialOrd);
+static_assertions::assert_impl_all!(StructPartialOrd2<NotPartialOrd>: PartialOrd);
+static_assertions::assert_impl_all!(TuplePartialOrd0: PartialOrd);
+static_assertions::assert_impl_all!(TuplePartialOrd1<NotPartialOrd>: PartialOrd);
+static_assertions::assert_impl_all!(TuplePartialOrd2<NotPartialOrd>: PartialOrd);
+static_assertions::assert_impl_all!(UnitPartialOrd: PartialOrd);
+static_assertions::assert_impl_all!(EnumPartialOrd0: PartialOrd);
+static_assertions::assert_impl_all!(EnumPartialOrd1<NotPartialOrd>: PartialOrd);
+static_assertions::assert_impl_all!(EnumPartialOrd<NotPartialOrd>: PartialOrd);
+
+fn partial_cmp<T>(lhs: &T, rhs: &T) -> Option<Ordering>
+where
+    T: PartialOrd,
+{
+    PartialOrd::partial_cmp(lhs, rhs)
+}
+
+#[test]
+fn test_partial_ord_struct() {
+    assert_eq!(
+        partial_cmp(&StructPartialOrd0 {}, &StructPartialOrd0 {}),
+        Some(Ordering::Equal)
+    );
+
+    assert_eq!(
+        partial_cmp(
+            &StructPartialOrd1::<NotPartialOrd> { foo: PhantomData },
+            &StructPartialOrd1::<NotPartialOrd> { foo: PhantomData },
+        ),
+        Some(Ordering::Equal),
+    );
+
+    let value = |foo, baz| StructPartialOrd2::<NotPartialOrd> {
+        foo,
+        bar: PhantomData,
+        baz,
+    };
+
+    assert_eq!(partial_cmp(&value(2, 2.0), &value(2, 2.0)), Some(Ordering::Equal));
+    assert_eq!(partial_cmp(&value(2, 3.0), &value(3, 2.0)), Some(Ordering::Less));
+    assert_eq!(partial_cmp(&value(3, 2.0), &value(2, 3.0)), Some(Ordering::Greater));
+    assert_eq!(partial_cmp(&value(2, 2.0), &value(2, 3.0)), Some(Ordering::Less));
+    assert_eq!(partial_cmp(&value(2, f64::NAN), &value(2, 2.0)), None);
+    assert_eq!(partial_cmp(&value(2, f64::NAN), &value(3, 2.0)), Some(Ordering::Less));
+}
+
+#[test]
+fn test_partial_ord_tuple() {
+    assert_eq!(
+        partial_cmp(&TuplePartialOrd0(), &TuplePartialOrd0()),
+        Some(Ordering::Equal)
+    );
+
+    assert_eq!(
+        partial_cmp(
+            &TuplePartialOrd1::<NotPartialOrd>(PhantomData),
+            &TuplePartialOrd1::<NotPartialOrd>(PhantomData),
+        ),
+        Some(Ordering::Equal),
+    );
+
+    let value = |foo, baz| TuplePartialOrd2::<NotPartialOrd>(foo, PhantomData, baz);
+
+    assert_eq!(partial_cmp(&value(2, 2.0), &value(2, 2.0)), Some(Ordering::Equal));
+    assert_eq!(partial_cmp(&value(2, 3.0), &value(3, 2.0)), Some(Ordering::Less));
+    assert_eq!(partial_cmp(&value(3, 2.0), &value(2, 3.0)), Some(Ordering::Greater));
+    assert_eq!(partial_cmp(&value(2, 2.0), &value(2, 3.0)), Some(Ordering::Less));
+    assert_eq!(partial_cmp(&value(2, f64::NAN), &value(2, 2.0)), None);
+}
+
+#[test]
+fn test_partial_ord_unit() {
+    assert_eq!(partial_cmp(&UnitPartialOrd, &UnitPartialOrd), Some(Ordering::Equal));
+}
+
+#[test]
+fn test_partial_ord_enum_1() {
+    assert_eq!(
+        partial_cmp(
+            &EnumPartialOrd1::<NotPartialOrd>::Tuple1(PhantomData),
+            &EnumPartialOrd1::<NotPartialOrd>::Tuple1(PhantomData),
+        ),
+        Some(Ordering::Equal),
+    );
+}
+
+#[test]
+fn test_partial_ord_enum() {
+    let struct_0 = || EnumPartialOrd::<NotPartialOrd>::Struct0 {};
+    let struct_1 = || EnumPartialOrd::<NotPartialOrd>::Struct1 { foo: PhantomData };
+
+    let struct_2_2 = || EnumPartialOrd::<NotPartialOrd>::Struct2 {
+        foo: PhantomData,
+        bar: 2,
+    };
+
+    let struct_2_3 = || EnumPartialOrd::<NotPartialOrd>::Struct2 {
+        foo: PhantomData,
+        bar: 3,
+    };
+
+    let tuple_0 = EnumPartialOrd::<NotPartialOrd>::Tuple0;
+    let tuple_1 = || EnumPartialOrd::<NotPartialOrd>::Tuple1(PhantomData);
+    let tuple_2_2 = || EnumPartialOrd::<NotPartialOrd>::Tuple2(PhantomData, 2);
+    let tuple_2_3 = || EnumPartialOrd::<NotPartialOrd>::Tuple2(PhantomData, 3);
+    let unit = || EnumPartialOrd::<NotPartialOrd>::Unit;
+
+    let all_values = [
+        struct_0, struct_1, struct_2_2, struct_2_3, tuple_0, tuple_1, tuple_2_2, tuple_2_3, unit,
+    ];
+
+    for (i, lhs) in all_values.iter().enumerate() {
+        for (j, rhs) in all_values.iter().enumerate() {
+            assert_eq!(partial_cmp(&lhs(), &rhs()), Some(i.cmp(&j)));
+        }
+    }
+}
+
+#[test]
+fn test_partial_ord_special_identifiers() {
+    let struct_value = |other, ordering| SpecialIdentifierStructPartialOrd { other, ordering };
+
+    assert_eq!(
+        partial_cmp(&struct_value(2, 3), &struct_value(2, 3)),
+        Some(Ordering::Equal)
+    );
+    assert_eq!(
+        partial_cmp(&struct_value(2, 3), &struct_value(3, 2)),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        partial_cmp(&struct_value(2, 3), &struct_value(2, 2)),
+        Some(Ordering::Greater)
+    );
+
+    let enum_value = |other, ordering| SpecialIdentifierEnumPartialOrd::Struct { other, ordering };
+
+    assert_eq!(partial_cmp(&enum_value(2, 3), &enum_value(2, 3)), Some(Ordering::Equal));
+    assert_eq!(partial_cmp(&enum_value(2, 3), &enum_value(3, 2)), Some(Ordering::Less));
+    assert_eq!(
+        partial_cmp(&enum_value(2, 3), &enum_value(2, 2)),
+        Some(Ordering::Greater)
+    );
+
+    assert_eq!(
+        partial_cmp(&enum_value(3, 3), &SpecialIdentifierEnumPartialOrd::Tuple(2)),
+        Some(Ordering::Less),
+    );
+}
@@ -0,0 +1,59 @@
+use crate::prelude::*;
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+
+struct NotImplemented;
+
+#[derive(
+    ForceClone,
+    ForceCopy,
+    ForceDebug,
+    ForceDefault,
+    ForceDisplay,
+    ForceEq,
+    ForceHash,
+    ForceOrd,
+    ForcePartialEq,
+    ForcePartialOrd,
+    ForceSend,
+    ForceSync,
+    ForceUnpin,
+)]
+#[force_derive(unsafe(Send, Sync))]
+#[display("Prelude")]
+struct Prelude<T> {
+    foo: PhantomData<T>,
+}
+
+static_assertions::assert_impl_all!(
+    Prelude<NotImplemented>: Clone,
+    Copy,
+    std::fmt::Debug,
+    Default,
+    std::fmt::Display,
+    Eq,
+    std::hash::Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Send,
+    Sync,
+    Unpin,
+);
+
+#[allow(dead_code)]
+#[derive(ForceFrom)]
+struct PreludeFrom<T>(u32, PhantomData<T>);
+
+static_assertions::assert_impl_all!(PreludeFrom<NotImplemented>: From<u32>);
+
+#[test]
+fn test_prelude() {
+    let value = Prelude::<NotImplemented>::default();
+
+    assert_eq!(
+        format!("{:?}", value.clone()),
+        "Prelude { foo: PhantomData<force_derive::tests::prelude::NotImplemented> }"
+    );
+    assert_eq!(value.cmp(&value), Ordering::Equal);
+}
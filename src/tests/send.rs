@@ -0,0 +1,42 @@
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+type NotSend = Rc<()>;
+
+#[derive(force_derive::Send)]
+#[force_derive(unsafe(Send))]
+struct HandleSend<T> {
+    id: u32,
+    marker: PhantomData<*const T>,
+}
+
+#[allow(dead_code)]
+#[derive(force_derive::Send)]
+#[force_derive(unsafe(Send))]
+enum EnumSend<T> {
+    Handle(PhantomData<*const T>),
+    Unit,
+}
+
+#[derive(force_derive::Send)]
+#[force_derive(unsafe(Send), bound(Send = "T: Send"))]
+struct BoundSend<T> {
+    foo: PhantomData<*const T>,
+}
+
+// Tests.
+
+static_assertions::assert_impl_all!(HandleSend<NotSend>: Send);
+static_assertions::assert_impl_all!(EnumSend<NotSend>: Send);
+static_assertions::assert_impl_all!(BoundSend<u32>: Send);
+static_assertions::assert_not_impl_any!(BoundSend<NotSend>: Send);
+
+#[test]
+fn test_send() {
+    let handle = HandleSend::<NotSend> {
+        id: 2,
+        marker: PhantomData,
+    };
+
+    assert_eq!(std::thread::spawn(move || handle.id).join().unwrap(), 2);
+}
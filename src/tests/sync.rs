@@ -0,0 +1,42 @@
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+type NotSync = Rc<()>;
+
+#[derive(force_derive::Sync)]
+#[force_derive(unsafe(Sync))]
+struct HandleSync<T> {
+    id: u32,
+    marker: PhantomData<*const T>,
+}
+
+#[allow(dead_code)]
+#[derive(force_derive::Sync)]
+#[force_derive(unsafe(Sync))]
+enum EnumSync<T> {
+    Handle(PhantomData<*const T>),
+    Unit,
+}
+
+#[derive(force_derive::Sync)]
+#[force_derive(unsafe(Sync), bound(Sync = "T: Sync"))]
+struct BoundSync<T> {
+    foo: PhantomData<*const T>,
+}
+
+// Tests.
+
+static_assertions::assert_impl_all!(HandleSync<NotSync>: Sync);
+static_assertions::assert_impl_all!(EnumSync<NotSync>: Sync);
+static_assertions::assert_impl_all!(BoundSync<u32>: Sync);
+static_assertions::assert_not_impl_any!(BoundSync<NotSync>: Sync);
+
+#[test]
+fn test_sync() {
+    let handle = HandleSync::<NotSync> {
+        id: 2,
+        marker: PhantomData,
+    };
+
+    assert_eq!(std::thread::scope(|scope| scope.spawn(|| handle.id).join().unwrap()), 2);
+}
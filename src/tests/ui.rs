@@ -0,0 +1,4 @@
+#[test]
+fn test_ui() {
+    trybuild::TestCases::new().compile_fail("src/tests/ui/*.rs");
+}
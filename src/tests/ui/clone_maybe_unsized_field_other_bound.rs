@@ -0,0 +1,8 @@
+#[derive(force_derive::Clone)]
+#[force_derive(bound(Clone = "T: Clone"))]
+struct Packet<T, H: ?Sized> {
+    len: T,
+    header: H,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(force_derive::Clone)]
+struct Foo {
+    len: u32,
+    data: [u8],
+}
+
+fn main() {}
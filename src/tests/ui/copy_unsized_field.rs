@@ -0,0 +1,4 @@
+#[derive(force_derive::Clone, force_derive::Copy)]
+struct Foo(u32, dyn Fn());
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[derive(force_derive::Debug)]
+struct Foo(#[debug(rename = "foo")] u32);
+
+fn main() {}
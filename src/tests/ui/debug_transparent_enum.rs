@@ -0,0 +1,7 @@
+#[derive(force_derive::Debug)]
+#[debug(transparent)]
+enum Foo {
+    Bar(u32),
+}
+
+fn main() {}
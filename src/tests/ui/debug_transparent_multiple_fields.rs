@@ -0,0 +1,7 @@
+use std::marker::PhantomData;
+
+#[derive(force_derive::Debug)]
+#[debug(transparent)]
+struct Foo<T>(u64, PhantomData<T>, u32);
+
+fn main() {}
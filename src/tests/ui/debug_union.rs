@@ -0,0 +1,7 @@
+#[derive(force_derive::Debug)]
+union Foo {
+    foo: u32,
+    bar: f32,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(force_derive::Debug)]
+#[debug(unknown)]
+struct Foo {
+    foo: u32,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(force_derive::Debug)]
+struct Foo {
+    #[debug(unknown)]
+    foo: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(force_derive::Default)]
+enum Foo {
+    #[default]
+    A,
+    #[default]
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(force_derive::Default)]
+enum Foo {
+    A,
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(force_derive::Default)]
+struct Foo {
+    #[default(1, 2)]
+    foo: u32,
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[derive(force_derive::Default)]
+struct Foo(#[default(1)] #[default(2)] u32);
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(force_derive::Default)]
+struct Foo {
+    #[default]
+    foo: u32,
+}
+
+fn main() {}
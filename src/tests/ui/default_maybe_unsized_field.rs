@@ -0,0 +1,7 @@
+#[derive(force_derive::Default)]
+struct Packet<H: ?Sized> {
+    len: u32,
+    header: H,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(force_derive::Default)]
+union Foo {
+    #[default]
+    #[default(2)]
+    foo: u32,
+}
+
+fn main() {}
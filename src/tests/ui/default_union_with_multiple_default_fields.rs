@@ -0,0 +1,9 @@
+#[derive(force_derive::Default)]
+union Foo {
+    #[default]
+    foo: u32,
+    #[default]
+    bar: f32,
+}
+
+fn main() {}
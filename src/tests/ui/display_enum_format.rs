@@ -0,0 +1,7 @@
+#[derive(force_derive::Display)]
+#[display("Foo")]
+enum Foo {
+    Bar,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[derive(force_derive::Display)]
+#[display("{0}")]
+struct Foo(u32);
+
+#[derive(force_derive::Display)]
+#[display("{}")]
+struct Bar {
+    baz: u32,
+}
+
+fn main() {}
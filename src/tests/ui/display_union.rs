@@ -0,0 +1,6 @@
+#[derive(force_derive::Display)]
+union Foo {
+    bar: u32,
+}
+
+fn main() {}
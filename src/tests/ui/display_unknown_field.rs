@@ -0,0 +1,7 @@
+#[derive(force_derive::Display)]
+#[display("{baz}")]
+struct Foo {
+    bar: u32,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[derive(force_derive::Display)]
+struct Foo {
+    bar: u32,
+}
+
+fn main() {}
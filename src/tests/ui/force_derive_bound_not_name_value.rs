@@ -0,0 +1,5 @@
+#[derive(force_derive::Clone)]
+#[force_derive(bound(Clone))]
+struct Foo<T>(T);
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(force_derive::Clone)]
+#[force_derive(bound(Iterator = "T: Iterator"))]
+struct Foo<T>(T);
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(force_derive::PartialEq)]
+struct Foo {
+    #[force_derive(skip(Clone))]
+    foo: u32,
+}
+
+fn main() {}
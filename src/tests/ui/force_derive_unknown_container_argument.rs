@@ -0,0 +1,5 @@
+#[derive(force_derive::Clone)]
+#[force_derive(unknown)]
+struct Foo;
+
+fn main() {}
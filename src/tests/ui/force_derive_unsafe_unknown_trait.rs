@@ -0,0 +1,5 @@
+#[derive(force_derive::Clone)]
+#[force_derive(unsafe(Clone))]
+struct Foo;
+
+fn main() {}
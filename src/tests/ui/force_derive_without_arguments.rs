@@ -0,0 +1,5 @@
+#[derive(force_derive::Clone)]
+#[force_derive]
+struct Foo;
+
+fn main() {}
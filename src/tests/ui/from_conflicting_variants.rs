@@ -0,0 +1,9 @@
+#[derive(force_derive::From)]
+enum Foo {
+    #[from]
+    Bar(u32),
+    #[from]
+    Baz(u32),
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[derive(force_derive::From)]
+enum Foo {
+    Bar(u32),
+}
+
+fn main() {}
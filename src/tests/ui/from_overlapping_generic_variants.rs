@@ -0,0 +1,9 @@
+#[derive(force_derive::From)]
+enum Foo<T, U> {
+    #[from]
+    Bar(T),
+    #[from]
+    Baz(U),
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[derive(force_derive::From)]
+union Foo {
+    bar: u32,
+}
+
+fn main() {}
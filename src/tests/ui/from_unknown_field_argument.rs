@@ -0,0 +1,4 @@
+#[derive(force_derive::From)]
+struct Foo(#[from(skip)] u32, u32);
+
+fn main() {}
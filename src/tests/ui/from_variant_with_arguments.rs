@@ -0,0 +1,7 @@
+#[derive(force_derive::From)]
+enum Foo {
+    #[from(default)]
+    Bar(u32),
+}
+
+fn main() {}
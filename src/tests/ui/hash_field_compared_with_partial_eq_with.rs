@@ -0,0 +1,11 @@
+fn approx_eq(lhs: &f64, rhs: &f64) -> bool {
+    (lhs - rhs).abs() < 1e-9
+}
+
+#[derive(force_derive::Hash, force_derive::PartialEq)]
+struct Foo {
+    #[partial_eq(with = approx_eq)]
+    foo: f64,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(force_derive::Hash)]
+union Foo {
+    foo: u32,
+    bar: f32,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(force_derive::Hash)]
+struct Foo {
+    #[hash(key = to_bits)]
+    foo: f64,
+}
+
+fn main() {}
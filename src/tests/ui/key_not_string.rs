@@ -0,0 +1,7 @@
+#[derive(force_derive::PartialEq)]
+struct Foo {
+    #[force_derive(key = 3)]
+    foo: f64,
+}
+
+fn main() {}
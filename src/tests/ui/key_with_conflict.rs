@@ -0,0 +1,12 @@
+#[derive(force_derive::PartialEq)]
+struct Foo {
+    #[force_derive(key = "f64::to_bits")]
+    #[partial_eq(with = approx_eq)]
+    foo: f64,
+}
+
+fn approx_eq(lhs: &f64, rhs: &f64) -> bool {
+    (lhs - rhs).abs() < 0.001
+}
+
+fn main() {}
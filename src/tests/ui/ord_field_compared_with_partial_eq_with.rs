@@ -0,0 +1,11 @@
+fn approx_eq(lhs: &u32, rhs: &u32) -> bool {
+    lhs / 10 == rhs / 10
+}
+
+#[derive(force_derive::PartialEq, force_derive::Ord)]
+struct Foo {
+    #[partial_eq(with = approx_eq)]
+    foo: u32,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(force_derive::PartialEq)]
+struct Foo {
+    #[partial_eq(by = approx_eq)]
+    foo: f64,
+}
+
+fn main() {}
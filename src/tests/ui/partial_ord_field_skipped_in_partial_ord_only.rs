@@ -0,0 +1,7 @@
+#[derive(force_derive::PartialEq, force_derive::PartialOrd)]
+struct Foo {
+    #[force_derive(skip(PartialOrd))]
+    foo: u32,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use std::marker::PhantomData;
+
+#[derive(force_derive::Send)]
+struct Foo<T> {
+    foo: PhantomData<*const T>,
+}
+
+fn main() {}
@@ -0,0 +1,64 @@
+use std::future::Future;
+use std::marker::{PhantomData, PhantomPinned};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+type NotUnpin = PhantomPinned;
+
+#[allow(dead_code)]
+#[derive(force_derive::Unpin)]
+struct PhantomUnpin<T> {
+    foo: PhantomData<T>,
+}
+
+#[allow(dead_code)]
+#[derive(force_derive::Unpin)]
+struct FieldUnpin<T, U> {
+    foo: PhantomData<T>,
+    bar: Box<T>,
+    baz: U,
+}
+
+#[allow(dead_code)]
+#[derive(force_derive::Unpin)]
+enum EnumUnpin<T, U> {
+    Phantom(PhantomData<T>),
+    Value(U),
+}
+
+#[derive(force_derive::Unpin)]
+struct Ready<T> {
+    value: Option<u32>,
+    marker: PhantomData<T>,
+}
+
+impl<T> Future for Ready<T> {
+    type Output = u32;
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context) -> Poll<u32> {
+        Poll::Ready(self.value.take().unwrap())
+    }
+}
+
+// Tests.
+
+static_assertions::assert_impl_all!(PhantomUnpin<NotUnpin>: Unpin);
+static_assertions::assert_impl_all!(FieldUnpin<NotUnpin, u32>: Unpin);
+static_assertions::assert_not_impl_any!(FieldUnpin<u32, NotUnpin>: Unpin);
+static_assertions::assert_impl_all!(EnumUnpin<NotUnpin, u32>: Unpin);
+static_assertions::assert_not_impl_any!(EnumUnpin<u32, NotUnpin>: Unpin);
+
+#[test]
+fn test_unpin() {
+    let mut future = Ready::<NotUnpin> {
+        value: Some(2),
+        marker: PhantomData,
+    };
+
+    let waker = std::task::Waker::noop();
+
+    assert_eq!(
+        Pin::new(&mut future).poll(&mut Context::from_waker(waker)),
+        Poll::Ready(2)
+    );
+}